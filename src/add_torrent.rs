@@ -1,5 +1,6 @@
 //! Torrent upload via multipart form requests.
 
+use crate::client::success_response;
 use crate::{QBittorrentClient, Response, Status};
 use colored::Colorize;
 use log::{debug, trace};
@@ -37,18 +38,44 @@ impl QBittorrentClient {
         &self,
         options: AddTorrentOptions,
         torrents: Vec<PathBuf>,
+    ) -> Result<Response<bool>, Failure<AddTorrentAction>> {
+        self.add_torrent_sources(options, TorrentSource::Files(torrents))
+            .await
+    }
+
+    /// Add torrents from URLs or magnet links
+    ///
+    /// The server downloads each `.torrent` URL itself.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#add-new-torrent>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#add-new-torrent>
+    pub async fn add_torrent_urls(
+        &self,
+        options: AddTorrentOptions,
+        urls: Vec<String>,
+    ) -> Result<Response<bool>, Failure<AddTorrentAction>> {
+        self.add_torrent_sources(options, TorrentSource::Urls(urls))
+            .await
+    }
+
+    async fn add_torrent_sources(
+        &self,
+        options: AddTorrentOptions,
+        source: TorrentSource,
     ) -> Result<Response<bool>, Failure<AddTorrentAction>> {
         self.ensure_login()
             .await
             .map_err(Failure::wrap(AddTorrentAction::Login))?;
         let options_retry = options.clone();
-        let torrents_retry = torrents.clone();
-        let response = self.send_add_torrents(options, torrents).await?;
+        let source_retry = source.clone();
+        let response = self.send_add_torrents(options, source).await?;
         if response.status().as_u16() == 403 {
             debug!(
                 "{} 403 response for add_torrents, re-authenticating",
                 "Received".bold()
             );
+            self.clear_session();
             let status = self
                 .login()
                 .await
@@ -57,35 +84,29 @@ impl QBittorrentClient {
                 return Err(Failure::from_action(AddTorrentAction::Login)
                     .with("status", format!("{status:?}")));
             }
-            let response = self
-                .send_add_torrents(options_retry, torrents_retry)
-                .await?;
-            let status = response.status();
-            return Ok(Response {
-                status_code: Some(status.as_u16()),
-                result: Some(status.is_success()),
-            });
+            let response = self.send_add_torrents(options_retry, source_retry).await?;
+            return Ok(success_response(&response));
         }
-        let status = response.status();
-        Ok(Response {
-            status_code: Some(status.as_u16()),
-            result: Some(status.is_success()),
-        })
+        Ok(success_response(&response))
     }
 
     async fn send_add_torrents(
         &self,
         options: AddTorrentOptions,
-        torrents: Vec<PathBuf>,
+        source: TorrentSource,
     ) -> Result<reqwest::Response, Failure<AddTorrentAction>> {
         let method = Method::POST;
         let endpoint = "/torrents/add";
         let url = format!("{}/api/v2{endpoint}", self.host);
+        let form = match source {
+            TorrentSource::Files(torrents) => options.to_form(torrents)?,
+            TorrentSource::Urls(urls) => options.to_form(Vec::new())?.text("urls", urls.join("\n")),
+        };
         let mut client = self.client.lock().await;
         let request = client
             .get_ref()
             .request(method.clone(), url.clone())
-            .multipart(options.to_form(torrents)?)
+            .multipart(form)
             .build()
             .map_err(Failure::wrap(AddTorrentAction::BuildRequest))?;
         let start = SystemTime::now();
@@ -104,6 +125,15 @@ impl QBittorrentClient {
     }
 }
 
+/// Torrents to add with `/torrents/add`.
+#[derive(Clone, Debug)]
+enum TorrentSource {
+    /// Paths of `.torrent` files uploaded to the server.
+    Files(Vec<PathBuf>),
+    /// URLs or magnet links downloaded by the server.
+    Urls(Vec<String>),
+}
+
 /// Options for adding torrents to qBittorrent.
 ///
 /// - Intentionally exposes only a subset of the parameters accepted by `POST /torrents/add`.
@@ -114,7 +144,7 @@ impl QBittorrentClient {
 /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#add-new-torrent>
 /// - <https://github.com/qbittorrent/qBittorrent/blob/release-4.1.0/src/webui/api/torrentscontroller.cpp#L453>
 /// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/webui/api/torrentscontroller.cpp#L693>
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
 pub struct AddTorrentOptions {
     /// Path to the downloads folder the torrent content is stored in
     pub save_path: Option<String>,
@@ -145,6 +175,28 @@ pub struct AddTorrentOptions {
 }
 
 impl AddTorrentOptions {
+    /// Resolve a relative `save_path` against the server's default save path.
+    ///
+    /// Absolute and unset save paths are left unchanged.
+    ///
+    /// Get the default save path with
+    /// [`QBittorrentClient::get_default_save_path`].
+    #[must_use]
+    pub fn resolve_save_path(mut self, default_save_path: &str) -> Self {
+        if let Some(save_path) = &self.save_path
+            && !is_absolute_path(save_path)
+        {
+            let base = default_save_path.trim_end_matches(['/', '\\']);
+            let separator = if base.contains('\\') && !base.contains('/') {
+                '\\'
+            } else {
+                '/'
+            };
+            self.save_path = Some(format!("{base}{separator}{save_path}"));
+        }
+        self
+    }
+
     /// Build a multipart form from these options and torrent files.
     pub fn to_form(self, torrents: Vec<PathBuf>) -> Result<Form, Failure<AddTorrentAction>> {
         let mut form = Form::new();
@@ -197,6 +249,16 @@ impl AddTorrentOptions {
     }
 }
 
+/// Whether `path` is absolute on either a Unix or Windows server.
+pub(crate) fn is_absolute_path(path: &str) -> bool {
+    let mut chars = path.chars();
+    match (chars.next(), chars.next()) {
+        (Some('/' | '\\'), _) => true,
+        (Some(drive), Some(':')) => drive.is_ascii_alphabetic(),
+        _ => false,
+    }
+}
+
 fn get_torrent_part(path: PathBuf) -> Result<Part, Failure<AddTorrentAction>> {
     let mut file =
         File::open(&path).map_err(Failure::wrap_with_path(AddTorrentAction::OpenFile, &path))?;
@@ -264,4 +326,38 @@ mod tests {
         let _result = response.get_result("add_torrents")?;
         Ok(())
     }
+
+    #[test]
+    fn resolve_relative_save_path() {
+        let options = AddTorrentOptions {
+            save_path: Some("music/flac".to_owned()),
+            ..AddTorrentOptions::default()
+        };
+        let options = options.resolve_save_path("/downloads/");
+        assert_eq!(options.save_path.as_deref(), Some("/downloads/music/flac"));
+    }
+
+    #[test]
+    fn resolve_relative_windows_save_path() {
+        let options = AddTorrentOptions {
+            save_path: Some("music".to_owned()),
+            ..AddTorrentOptions::default()
+        };
+        let options = options.resolve_save_path("C:\\Downloads");
+        assert_eq!(options.save_path.as_deref(), Some("C:\\Downloads\\music"));
+    }
+
+    #[test]
+    fn resolve_absolute_save_path() {
+        for save_path in ["/srv/shared/tests", "D:\\Torrents"] {
+            let options = AddTorrentOptions {
+                save_path: Some(save_path.to_owned()),
+                ..AddTorrentOptions::default()
+            };
+            let options = options.resolve_save_path("/downloads");
+            assert_eq!(options.save_path.as_deref(), Some(save_path));
+        }
+        let options = AddTorrentOptions::default().resolve_save_path("/downloads");
+        assert_eq!(options.save_path, None);
+    }
 }
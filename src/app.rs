@@ -0,0 +1,357 @@
+//! Application version and build queries, endpoint capability checks and shutdown.
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::client::{ClientAction, deserialize_response, success_response, text_response};
+use crate::{QBittorrentClient, Response};
+use reqwest::Method;
+use rogue_logging::Failure;
+use serde::{Deserialize, Serialize};
+
+/// First qBittorrent version with `/app/buildInfo`.
+const BUILD_INFO_VERSION: Version = Version::new(4, 2, 0);
+
+/// First libtorrent version supporting v2 torrents and SHA-256 info hashes.
+const LIBTORRENT_V2_VERSION: Version = Version::new(2, 0, 0);
+
+impl QBittorrentClient {
+    /// Get the qBittorrent application version
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-application-version>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-application-version>
+    pub async fn get_version(&self) -> Result<Response<Version>, Failure<ClientAction>> {
+        self.get_version_from("/app/version").await
+    }
+
+    /// Get the `WebUI` API version
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-api-version>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-api-version>
+    pub async fn get_webapi_version(&self) -> Result<Response<Version>, Failure<ClientAction>> {
+        self.get_version_from("/app/webapiVersion").await
+    }
+
+    /// Get the versions of the libraries qBittorrent was built with
+    ///
+    /// Added in v4.2. Returns [`ClientAction::CheckSupport`] on older servers.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-build-info>
+    pub async fn get_build_info(&self) -> Result<Response<BuildInfo>, Failure<ClientAction>> {
+        let method = Method::GET;
+        let endpoint = "/app/buildInfo";
+        self.require_version(BUILD_INFO_VERSION, endpoint).await?;
+        let response = self
+            .request_with_login(method.clone(), endpoint, &())
+            .await?;
+        deserialize_response::<BuildInfo>(&method, endpoint, response).await
+    }
+
+    /// Get the default save path for torrents
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-default-save-path>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-default-save-path>
+    pub async fn get_default_save_path(&self) -> Result<Response<String>, Failure<ClientAction>> {
+        let method = Method::GET;
+        let endpoint = "/app/defaultSavePath";
+        let response = self
+            .request_with_login(method.clone(), endpoint, &())
+            .await?;
+        text_response(&method, endpoint, response).await
+    }
+
+    /// Shut down the qBittorrent application
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#shutdown-application>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#shutdown-application>
+    pub async fn shutdown(&self) -> Result<Response<bool>, Failure<ClientAction>> {
+        let response = self
+            .request_with_login(Method::POST, "/app/shutdown", &())
+            .await?;
+        Ok(success_response(&response))
+    }
+
+    /// Get the qBittorrent application version, requesting it only once per client.
+    pub async fn server_version(&self) -> Result<Version, Failure<ClientAction>> {
+        let version = self
+            .version
+            .get_or_try_init(|| async { self.get_version().await?.get_result("get_version") })
+            .await?;
+        Ok(*version)
+    }
+
+    /// Fail with [`ClientAction::CheckSupport`] if the server is older than `required`.
+    pub(crate) async fn require_version(
+        &self,
+        required: Version,
+        endpoint: &str,
+    ) -> Result<(), Failure<ClientAction>> {
+        let version = self.server_version().await?;
+        if version < required {
+            return Err(Failure::from_action(ClientAction::CheckSupport)
+                .with("endpoint", endpoint)
+                .with("version", version.to_string())
+                .with("required_version", required.to_string()));
+        }
+        Ok(())
+    }
+
+    async fn get_version_from(
+        &self,
+        endpoint: &str,
+    ) -> Result<Response<Version>, Failure<ClientAction>> {
+        let method = Method::GET;
+        let response = self
+            .request_with_login(method.clone(), endpoint, &())
+            .await?;
+        let response = text_response(&method, endpoint, response).await?;
+        let Some(text) = response.result else {
+            return Ok(Response {
+                status_code: response.status_code,
+                result: None,
+            });
+        };
+        let version = Version::parse(&text).ok_or_else(|| {
+            Failure::from_action(ClientAction::DeserializeResponse)
+                .with("endpoint", endpoint)
+                .with("version", text.clone())
+        })?;
+        Ok(Response {
+            status_code: response.status_code,
+            result: Some(version),
+        })
+    }
+}
+
+/// Versions of the libraries qBittorrent was built with.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-build-info>
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/webui/api/appcontroller.cpp>
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BuildInfo {
+    /// Qt version.
+    pub qt: String,
+
+    /// libtorrent version.
+    pub libtorrent: String,
+
+    /// Boost version.
+    pub boost: String,
+
+    /// OpenSSL version.
+    pub openssl: String,
+
+    /// zlib version.
+    ///
+    /// Not returned by older servers.
+    pub zlib: Option<String>,
+
+    /// Application bitness, `32` or `64`.
+    pub bitness: u8,
+
+    /// Operating system platform.
+    ///
+    /// Not returned by older servers.
+    pub platform: Option<String>,
+}
+
+impl BuildInfo {
+    /// Parse the libtorrent version.
+    #[must_use]
+    pub fn libtorrent_version(&self) -> Option<Version> {
+        Version::parse(&self.libtorrent)
+    }
+
+    /// Whether libtorrent supports v2 torrents and SHA-256 info hashes.
+    #[must_use]
+    pub fn is_v2_supported(&self) -> bool {
+        self.libtorrent_version()
+            .is_some_and(|version| version >= LIBTORRENT_V2_VERSION)
+    }
+}
+
+#[cfg(feature = "mock")]
+impl BuildInfo {
+    /// Create a mock `BuildInfo` for testing
+    #[must_use]
+    pub fn mock() -> Self {
+        serde_json::from_str(include_str!("tests/fixtures/app_build_info_v5.json"))
+            .expect("v5 fixture should deserialize")
+    }
+}
+
+/// A `major.minor.patch` version of qBittorrent or its `WebUI` API.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize,
+)]
+pub struct Version {
+    /// Major version.
+    pub major: u32,
+    /// Minor version.
+    pub minor: u32,
+    /// Patch version.
+    pub patch: u32,
+}
+
+impl Version {
+    /// Create a version from its components.
+    #[must_use]
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Parse a version such as `v4.6.7`, `5.0.0beta1` or `2.11.4`.
+    ///
+    /// - A leading `v` and surrounding whitespace are ignored.
+    /// - Missing minor or patch components default to `0`.
+    /// - Non-numeric suffixes such as `beta1` or `-rc1` are ignored.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        let value = value.strip_prefix('v').unwrap_or(value);
+        let mut components = value.split('.').map(|component| {
+            let digits: String = component.chars().take_while(char::is_ascii_digit).collect();
+            digits.parse::<u32>().ok()
+        });
+        let major = components.next()??;
+        let minor = components.next().flatten().unwrap_or_default();
+        let patch = components.next().flatten().unwrap_or_default();
+        Some(Self::new(major, minor, patch))
+    }
+}
+
+impl Display for Version {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QBittorrentClientOptions;
+    use crate::tests::init_logger;
+    use log::trace;
+    use rogue_config::{OptionsProvider, YamlOptionsProvider};
+    use std::error::Error;
+
+    const BUILD_INFO_V4: &str = include_str!("tests/fixtures/app_build_info_v4.json");
+    const BUILD_INFO_V5: &str = include_str!("tests/fixtures/app_build_info_v5.json");
+
+    #[tokio::test]
+    #[ignore = "integration test requiring API credentials"]
+    async fn get_version() -> Result<(), Box<dyn Error>> {
+        // Arrange
+        init_logger();
+        let options: QBittorrentClientOptions =
+            YamlOptionsProvider::get().map_err(|e| e.to_string())?;
+        let client = QBittorrentClient::from_options(options);
+
+        // Act
+        let response = client.get_version().await?;
+        trace!("{}", response.to_json_pretty());
+
+        // Assert
+        let result = response.get_result("get_version")?;
+        assert!(result >= Version::new(4, 1, 0));
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "integration test requiring API credentials"]
+    async fn get_build_info() -> Result<(), Box<dyn Error>> {
+        // Arrange
+        init_logger();
+        let options: QBittorrentClientOptions =
+            YamlOptionsProvider::get().map_err(|e| e.to_string())?;
+        let client = QBittorrentClient::from_options(options);
+
+        // Act
+        let response = client.get_build_info().await?;
+        trace!("{}", response.to_json_pretty());
+
+        // Assert
+        let result = response.get_result("get_build_info")?;
+        assert!(result.libtorrent_version().is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "integration test requiring API credentials"]
+    async fn get_default_save_path() -> Result<(), Box<dyn Error>> {
+        // Arrange
+        init_logger();
+        let options: QBittorrentClientOptions =
+            YamlOptionsProvider::get().map_err(|e| e.to_string())?;
+        let client = QBittorrentClient::from_options(options);
+
+        // Act
+        let response = client.get_default_save_path().await?;
+        trace!("{}", response.to_json_pretty());
+
+        // Assert
+        let result = response.get_result("get_default_save_path")?;
+        assert!(!result.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_build_info_v4() {
+        let info: BuildInfo =
+            serde_json::from_str(BUILD_INFO_V4).expect("v4 fixture should deserialize");
+        assert_eq!(info.zlib, None);
+        assert_eq!(info.libtorrent_version(), Some(Version::new(1, 2, 19)));
+        assert!(!info.is_v2_supported());
+    }
+
+    #[test]
+    fn deserialize_build_info_v5() {
+        let info: BuildInfo =
+            serde_json::from_str(BUILD_INFO_V5).expect("v5 fixture should deserialize");
+        assert_eq!(info.bitness, 64);
+        assert_eq!(info.libtorrent_version(), Some(Version::new(2, 0, 10)));
+        assert!(info.is_v2_supported());
+    }
+
+    #[test]
+    fn parse_application_version() {
+        assert_eq!(Version::parse("v4.6.7"), Some(Version::new(4, 6, 7)));
+    }
+
+    #[test]
+    fn parse_api_version() {
+        assert_eq!(Version::parse("2.11.4\n"), Some(Version::new(2, 11, 4)));
+    }
+
+    #[test]
+    fn parse_pre_release_version() {
+        assert_eq!(Version::parse("v5.0.0beta1"), Some(Version::new(5, 0, 0)));
+        assert_eq!(Version::parse("v4.6.0-rc1"), Some(Version::new(4, 6, 0)));
+    }
+
+    #[test]
+    fn parse_short_version() {
+        assert_eq!(Version::parse("v5.1"), Some(Version::new(5, 1, 0)));
+    }
+
+    #[test]
+    fn parse_invalid_version() {
+        assert_eq!(Version::parse(""), None);
+        assert_eq!(Version::parse("Forbidden"), None);
+    }
+
+    #[test]
+    fn version_ordering() {
+        assert!(Version::new(4, 10, 0) > Version::new(4, 6, 7));
+        assert!(Version::new(5, 0, 0) > Version::new(4, 6, 7));
+    }
+}
@@ -0,0 +1,347 @@
+//! Category listing and management.
+
+use std::collections::HashMap;
+
+use crate::client::{ClientAction, deserialize_response, join_hashes, success_response};
+use crate::{QBittorrentClient, Response};
+use reqwest::Method;
+use rogue_logging::Failure;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use thiserror::Error;
+
+impl QBittorrentClient {
+    /// Get all categories keyed by name
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-all-categories>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-all-categories>
+    /// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/webui/api/torrentscontroller.cpp>
+    pub async fn get_categories(
+        &self,
+    ) -> Result<Response<HashMap<String, Category>>, Failure<ClientAction>> {
+        let method = Method::GET;
+        let endpoint = "/torrents/categories";
+        let response = self
+            .request_with_login(method.clone(), endpoint, &())
+            .await?;
+        deserialize_response::<HashMap<String, Category>>(&method, endpoint, response).await
+    }
+
+    /// Create a new category
+    ///
+    /// Returns [`CategoryAction::InvalidName`] if the name is empty or rejected by the server.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#add-new-category>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#add-new-category>
+    pub async fn create_category(
+        &self,
+        category: Category,
+    ) -> Result<Response<bool>, Failure<CategoryAction>> {
+        let endpoint = "/torrents/createCategory";
+        let response = self
+            .request_with_login(Method::POST, endpoint, &category.to_form())
+            .await
+            .map_err(Failure::wrap(CategoryAction::Request))?;
+        match response.status().as_u16() {
+            400 | 409 => Err(Failure::from_action(CategoryAction::InvalidName)
+                .with("category", category.name)
+                .with("status_code", response.status().as_u16().to_string())),
+            _ => Ok(success_response(&response)),
+        }
+    }
+
+    /// Edit the save path of an existing category
+    ///
+    /// Returns [`CategoryAction::EditCategory`] if the server rejects the change,
+    /// typically because the category does not exist.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#edit-category>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#edit-category>
+    pub async fn edit_category(
+        &self,
+        category: Category,
+    ) -> Result<Response<bool>, Failure<CategoryAction>> {
+        let endpoint = "/torrents/editCategory";
+        let response = self
+            .request_with_login(Method::POST, endpoint, &category.to_form())
+            .await
+            .map_err(Failure::wrap(CategoryAction::Request))?;
+        match response.status().as_u16() {
+            400 => Err(Failure::from_action(CategoryAction::InvalidName)
+                .with("category", category.name)
+                .with("status_code", "400")),
+            409 => Err(Failure::from_action(CategoryAction::EditCategory)
+                .with("category", category.name)
+                .with("status_code", "409")),
+            _ => Ok(success_response(&response)),
+        }
+    }
+
+    /// Remove categories by name
+    ///
+    /// Torrents in a removed category are left uncategorized.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#remove-categories>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#remove-categories>
+    pub async fn remove_categories(
+        &self,
+        names: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        let endpoint = "/torrents/removeCategories";
+        let data = vec![("categories", names.join("\n"))];
+        let response = self
+            .request_with_login(Method::POST, endpoint, &data)
+            .await?;
+        Ok(success_response(&response))
+    }
+
+    /// Set the category of torrents
+    ///
+    /// An empty `name` removes the category from the torrents.
+    ///
+    /// Returns [`CategoryAction::CategoryNotFound`] if the category does not exist.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#set-torrent-category>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#set-torrent-category>
+    pub async fn set_torrent_category(
+        &self,
+        hashes: Vec<String>,
+        name: String,
+    ) -> Result<Response<bool>, Failure<CategoryAction>> {
+        let endpoint = "/torrents/setCategory";
+        let data = vec![("hashes", join_hashes(&hashes)), ("category", name.clone())];
+        let response = self
+            .request_with_login(Method::POST, endpoint, &data)
+            .await
+            .map_err(Failure::wrap(CategoryAction::Request))?;
+        if response.status().as_u16() == 409 {
+            return Err(Failure::from_action(CategoryAction::CategoryNotFound)
+                .with("category", name)
+                .with("status_code", "409"));
+        }
+        Ok(success_response(&response))
+    }
+}
+
+/// A torrent category.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-4.1.0/src/webui/api/torrentscontroller.cpp>
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/base/bittorrent/categoryoptions.cpp>
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Category {
+    /// Category name.
+    pub name: String,
+
+    /// Path where torrents in this category are saved.
+    ///
+    /// Empty to use the default save path.
+    #[serde(rename = "savePath")]
+    pub save_path: String,
+
+    /// Path where incomplete torrents in this category are downloaded.
+    ///
+    /// Added in v4.5
+    #[serde(default, skip_serializing_if = "CategoryDownloadPath::is_unset")]
+    pub download_path: CategoryDownloadPath,
+}
+
+impl Category {
+    /// Build the form parameters for `createCategory` and `editCategory`.
+    pub(crate) fn to_form(&self) -> Vec<(&'static str, String)> {
+        let mut form = vec![
+            ("category", self.name.clone()),
+            ("savePath", self.save_path.clone()),
+        ];
+        match &self.download_path {
+            CategoryDownloadPath::Unset => {}
+            CategoryDownloadPath::Disabled => {
+                form.push(("downloadPathEnabled", false.to_string()));
+            }
+            CategoryDownloadPath::Path(download_path) => {
+                form.push(("downloadPathEnabled", true.to_string()));
+                form.push(("downloadPath", download_path.clone()));
+            }
+        }
+        form
+    }
+}
+
+#[cfg(feature = "mock")]
+impl Category {
+    /// Create a mock `Category` for testing
+    #[must_use]
+    pub fn mock() -> Self {
+        Self {
+            name: "music".to_owned(),
+            save_path: "/downloads/music".to_owned(),
+            download_path: CategoryDownloadPath::Unset,
+        }
+    }
+}
+
+/// Download path of a category.
+///
+/// Returned by the API as a path string when enabled, `false` when disabled, and omitted
+/// when not set.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum CategoryDownloadPath {
+    /// Not set for the category so the global download path setting applies.
+    ///
+    /// No download path parameters are sent when creating or editing a category.
+    #[default]
+    Unset,
+    /// Disabled for the category.
+    Disabled,
+    /// Incomplete torrents are downloaded to this path.
+    Path(String),
+}
+
+impl CategoryDownloadPath {
+    /// Whether the download path is not set.
+    #[must_use]
+    pub fn is_unset(&self) -> bool {
+        *self == CategoryDownloadPath::Unset
+    }
+
+    /// The download path if enabled.
+    #[must_use]
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            CategoryDownloadPath::Path(path) => Some(path),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CategoryDownloadPath {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match Option::<Value>::deserialize(deserializer)? {
+            Some(Value::String(path)) => CategoryDownloadPath::Path(path),
+            Some(Value::Bool(false)) => CategoryDownloadPath::Disabled,
+            _ => CategoryDownloadPath::Unset,
+        })
+    }
+}
+
+impl Serialize for CategoryDownloadPath {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            CategoryDownloadPath::Unset => serializer.serialize_none(),
+            CategoryDownloadPath::Disabled => serializer.serialize_bool(false),
+            CategoryDownloadPath::Path(path) => serializer.serialize_str(path),
+        }
+    }
+}
+
+/// Errors returned by category operations
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Error)]
+pub enum CategoryAction {
+    #[error("send request")]
+    Request,
+    #[error("validate category name")]
+    InvalidName,
+    #[error("edit category")]
+    EditCategory,
+    #[error("find category")]
+    CategoryNotFound,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QBittorrentClientOptions;
+    use crate::tests::init_logger;
+    use insta::assert_yaml_snapshot;
+    use log::trace;
+    use rogue_config::{OptionsProvider, YamlOptionsProvider};
+    use std::collections::BTreeMap;
+    use std::error::Error;
+
+    const FIXTURE_V4: &str = include_str!("tests/fixtures/torrents_categories_v4.json");
+    const FIXTURE_V5: &str = include_str!("tests/fixtures/torrents_categories_v5.json");
+
+    #[tokio::test]
+    #[ignore = "integration test requiring API credentials"]
+    async fn get_categories() -> Result<(), Box<dyn Error>> {
+        // Arrange
+        init_logger();
+        let options: QBittorrentClientOptions =
+            YamlOptionsProvider::get().map_err(|e| e.to_string())?;
+        let client = QBittorrentClient::from_options(options);
+
+        // Act
+        let response = client.get_categories().await?;
+        trace!("{}", response.to_json_pretty());
+
+        // Assert
+        let result = response.get_result("get_categories")?;
+        assert!(result.contains_key("example"));
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_v4_fixture() {
+        let categories: BTreeMap<String, Category> =
+            serde_json::from_str(FIXTURE_V4).expect("v4 fixture should deserialize");
+        assert_yaml_snapshot!(categories);
+    }
+
+    #[test]
+    fn deserialize_v5_fixture() {
+        let categories: BTreeMap<String, Category> =
+            serde_json::from_str(FIXTURE_V5).expect("v5 fixture should deserialize");
+        assert_yaml_snapshot!(categories);
+    }
+
+    #[test]
+    fn category_to_form_includes_download_path() {
+        let category = Category {
+            name: "music".to_owned(),
+            save_path: "/downloads/music".to_owned(),
+            download_path: CategoryDownloadPath::Path("/incomplete/music".to_owned()),
+        };
+        let form = category.to_form();
+        assert!(form.contains(&("category", "music".to_owned())));
+        assert!(form.contains(&("savePath", "/downloads/music".to_owned())));
+        assert!(form.contains(&("downloadPathEnabled", "true".to_owned())));
+        assert!(form.contains(&("downloadPath", "/incomplete/music".to_owned())));
+    }
+
+    #[test]
+    fn category_to_form_disables_download_path() {
+        let category = Category {
+            name: "music".to_owned(),
+            download_path: CategoryDownloadPath::Disabled,
+            ..Category::default()
+        };
+        let form = category.to_form();
+        assert!(form.contains(&("downloadPathEnabled", "false".to_owned())));
+        assert!(!form.iter().any(|(key, _)| *key == "downloadPath"));
+    }
+
+    #[test]
+    fn category_to_form_omits_unset_download_path() {
+        let category = Category {
+            name: "music".to_owned(),
+            ..Category::default()
+        };
+        let form = category.to_form();
+        assert_eq!(form.len(), 2);
+    }
+
+    #[test]
+    fn download_path_round_trip() {
+        let categories: BTreeMap<String, Category> =
+            serde_json::from_str(FIXTURE_V5).expect("v5 fixture should deserialize");
+        let json = serde_json::to_string(&categories).expect("categories should serialize");
+        let round_trip: BTreeMap<String, Category> =
+            serde_json::from_str(&json).expect("categories should deserialize");
+        assert_eq!(round_trip, categories);
+    }
+}
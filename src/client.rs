@@ -1,10 +1,32 @@
 //! Core HTTP client with rate-limited request handling and automatic login.
 
 use async_trait::async_trait;
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use crate::add_torrent::{AddTorrentAction, AddTorrentOptions};
+use crate::app::BuildInfo;
+use crate::app::Version;
+use crate::categories::{Category, CategoryAction};
+use crate::directory::{DirectoryAction, DirectoryContentMode, DirectoryEntry};
 use crate::get_torrents::{FilterOptions, Torrent};
+use crate::limits::ShareLimits;
+use crate::location::LocationAction;
+use crate::logs::{LogEntry, LogFilter, PeerLogEntry};
+use crate::peers::PeerAddr;
+use crate::peers::{PeerAction, PeersUpdate};
+use crate::pieces::{PieceAction, PieceState};
+use crate::preferences::{Preferences, PreferencesPatch};
+use crate::queue::QueueAction;
+use crate::rename::RenameAction;
+use crate::rss::{RssAction, RssFolder};
+use crate::rss_rules::AutoDownloadRule;
+use crate::search::{SearchAction, SearchQuery, SearchResults, SearchStatus};
+use crate::search_plugins::SearchPlugin;
+use crate::session_store::SessionStore;
+use crate::torrent_creator::{TorrentCreationStatus, TorrentCreationTask, TorrentCreatorAction};
+use crate::transfer::{SpeedLimitsMode, TransferInfo};
+use crate::webseeds::{WebSeed, WebSeedAction};
 #[cfg(test)]
 use crate::{QBittorrentClientFactory, QBittorrentClientOptions};
 use crate::{QBittorrentClientTrait, Response, Status};
@@ -16,9 +38,10 @@ use rogue_logging::Failure;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 use std::time::SystemTime;
 use thiserror::Error;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, OnceCell};
 use tower::limit::RateLimit;
 use tower::{Service, ServiceExt};
 
@@ -36,6 +59,12 @@ pub struct QBittorrentClient {
     pub(crate) cookies: Arc<Jar>,
     /// Rate-limited HTTP client.
     pub(crate) client: Mutex<RateLimit<Client>>,
+    /// Application version, requested once on first capability check.
+    pub(crate) version: OnceCell<Version>,
+    /// Storage to persist the session cookie across restarts.
+    pub(crate) session_store: Option<Arc<dyn SessionStore>>,
+    /// Whether the session cookie was loaded from the session store and not yet checked.
+    pub(crate) unverified_session: AtomicBool,
 }
 
 impl QBittorrentClient {
@@ -102,6 +131,7 @@ impl QBittorrentClient {
                 "{} 403 response for {method} {endpoint}, re-authenticating",
                 "Received".bold()
             );
+            self.clear_session();
             let status = self.login().await?;
             if status != Status::Success {
                 return Err(
@@ -114,6 +144,20 @@ impl QBittorrentClient {
     }
 }
 
+/// Convert a response into a [`Response<bool>`] indicating whether the status code was successful.
+pub(crate) fn success_response(response: &reqwest::Response) -> Response<bool> {
+    let status = response.status();
+    Response {
+        status_code: Some(status.as_u16()),
+        result: Some(status.is_success()),
+    }
+}
+
+/// Join torrent hashes with the `|` separator expected by the API.
+pub(crate) fn join_hashes(hashes: &[String]) -> String {
+    hashes.join("|")
+}
+
 /// Read the response body and parse it as a [`Status`].
 pub(crate) async fn handle_status_response(
     method: &Method,
@@ -130,6 +174,44 @@ pub(crate) async fn handle_status_response(
     Ok(Status::from(text.as_str()))
 }
 
+/// Read the response body as plain text into a [`Response<String>`].
+pub(crate) async fn text_response(
+    method: &Method,
+    endpoint: &str,
+    response: reqwest::Response,
+) -> Result<Response<String>, Failure<ClientAction>> {
+    let status_code = response.status().as_u16();
+    let text = response.text().await.map_err(|e| {
+        Failure::new(ClientAction::ReadResponseBody, e)
+            .with("method", method.to_string())
+            .with("endpoint", endpoint)
+            .with("status_code", status_code.to_string())
+    })?;
+    Ok(Response {
+        status_code: Some(status_code),
+        result: Some(text),
+    })
+}
+
+/// Read the response body as raw bytes into a [`Response<Vec<u8>>`].
+pub(crate) async fn bytes_response(
+    method: &Method,
+    endpoint: &str,
+    response: reqwest::Response,
+) -> Result<Response<Vec<u8>>, Failure<ClientAction>> {
+    let status_code = response.status().as_u16();
+    let bytes = response.bytes().await.map_err(|e| {
+        Failure::new(ClientAction::ReadResponseBody, e)
+            .with("method", method.to_string())
+            .with("endpoint", endpoint)
+            .with("status_code", status_code.to_string())
+    })?;
+    Ok(Response {
+        status_code: Some(status_code),
+        result: Some(bytes.to_vec()),
+    })
+}
+
 /// Read the response body and deserialize it as JSON into a [`Response<T>`].
 pub(crate) async fn deserialize_response<T: DeserializeOwned>(
     method: &Method,
@@ -180,6 +262,518 @@ impl QBittorrentClientTrait for QBittorrentClient {
     ) -> Result<Response<bool>, Failure<AddTorrentAction>> {
         QBittorrentClient::add_torrents(self, options, torrents).await
     }
+    async fn get_categories(
+        &self,
+    ) -> Result<Response<HashMap<String, Category>>, Failure<ClientAction>> {
+        QBittorrentClient::get_categories(self).await
+    }
+    async fn create_category(
+        &self,
+        category: Category,
+    ) -> Result<Response<bool>, Failure<CategoryAction>> {
+        QBittorrentClient::create_category(self, category).await
+    }
+    async fn edit_category(
+        &self,
+        category: Category,
+    ) -> Result<Response<bool>, Failure<CategoryAction>> {
+        QBittorrentClient::edit_category(self, category).await
+    }
+    async fn remove_categories(
+        &self,
+        names: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::remove_categories(self, names).await
+    }
+    async fn set_torrent_category(
+        &self,
+        hashes: Vec<String>,
+        name: String,
+    ) -> Result<Response<bool>, Failure<CategoryAction>> {
+        QBittorrentClient::set_torrent_category(self, hashes, name).await
+    }
+    async fn get_tags(&self) -> Result<Response<Vec<String>>, Failure<ClientAction>> {
+        QBittorrentClient::get_tags(self).await
+    }
+    async fn create_tags(
+        &self,
+        tags: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::create_tags(self, tags).await
+    }
+    async fn delete_tags(
+        &self,
+        tags: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::delete_tags(self, tags).await
+    }
+    async fn add_torrent_tags(
+        &self,
+        hashes: Vec<String>,
+        tags: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::add_torrent_tags(self, hashes, tags).await
+    }
+    async fn remove_torrent_tags(
+        &self,
+        hashes: Vec<String>,
+        tags: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::remove_torrent_tags(self, hashes, tags).await
+    }
+    async fn set_torrent_tags(
+        &self,
+        hashes: Vec<String>,
+        tags: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::set_torrent_tags(self, hashes, tags).await
+    }
+    async fn set_location(
+        &self,
+        hashes: Vec<String>,
+        path: String,
+    ) -> Result<Response<bool>, Failure<LocationAction>> {
+        QBittorrentClient::set_location(self, hashes, path).await
+    }
+    async fn set_save_path(
+        &self,
+        hashes: Vec<String>,
+        path: String,
+    ) -> Result<Response<bool>, Failure<LocationAction>> {
+        QBittorrentClient::set_save_path(self, hashes, path).await
+    }
+    async fn set_download_path(
+        &self,
+        hashes: Vec<String>,
+        path: String,
+    ) -> Result<Response<bool>, Failure<LocationAction>> {
+        QBittorrentClient::set_download_path(self, hashes, path).await
+    }
+    async fn recheck_torrents(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::recheck_torrents(self, hashes).await
+    }
+    async fn reannounce_torrents(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::reannounce_torrents(self, hashes).await
+    }
+    async fn rename_torrent(
+        &self,
+        hash: String,
+        name: String,
+    ) -> Result<Response<bool>, Failure<RenameAction>> {
+        QBittorrentClient::rename_torrent(self, hash, name).await
+    }
+    async fn rename_file(
+        &self,
+        hash: String,
+        old_path: String,
+        new_path: String,
+    ) -> Result<Response<bool>, Failure<RenameAction>> {
+        QBittorrentClient::rename_file(self, hash, old_path, new_path).await
+    }
+    async fn rename_folder(
+        &self,
+        hash: String,
+        old_path: String,
+        new_path: String,
+    ) -> Result<Response<bool>, Failure<RenameAction>> {
+        QBittorrentClient::rename_folder(self, hash, old_path, new_path).await
+    }
+    async fn get_download_limits(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<HashMap<String, i64>>, Failure<ClientAction>> {
+        QBittorrentClient::get_download_limits(self, hashes).await
+    }
+    async fn set_download_limit(
+        &self,
+        hashes: Vec<String>,
+        limit: u64,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::set_download_limit(self, hashes, limit).await
+    }
+    async fn get_upload_limits(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<HashMap<String, i64>>, Failure<ClientAction>> {
+        QBittorrentClient::get_upload_limits(self, hashes).await
+    }
+    async fn set_upload_limit(
+        &self,
+        hashes: Vec<String>,
+        limit: u64,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::set_upload_limit(self, hashes, limit).await
+    }
+    async fn set_share_limits(
+        &self,
+        hashes: Vec<String>,
+        limits: ShareLimits,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::set_share_limits(self, hashes, limits).await
+    }
+    async fn set_force_start(
+        &self,
+        hashes: Vec<String>,
+        enabled: bool,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::set_force_start(self, hashes, enabled).await
+    }
+    async fn set_super_seeding(
+        &self,
+        hashes: Vec<String>,
+        enabled: bool,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::set_super_seeding(self, hashes, enabled).await
+    }
+    async fn set_auto_management(
+        &self,
+        hashes: Vec<String>,
+        enabled: bool,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::set_auto_management(self, hashes, enabled).await
+    }
+    async fn toggle_sequential_download(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::toggle_sequential_download(self, hashes).await
+    }
+    async fn toggle_first_last_piece_priority(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::toggle_first_last_piece_priority(self, hashes).await
+    }
+    async fn set_sequential_download(
+        &self,
+        hashes: Vec<String>,
+        enabled: bool,
+    ) -> Result<Response<Vec<String>>, Failure<ClientAction>> {
+        QBittorrentClient::set_sequential_download(self, hashes, enabled).await
+    }
+    async fn set_first_last_piece_priority(
+        &self,
+        hashes: Vec<String>,
+        enabled: bool,
+    ) -> Result<Response<Vec<String>>, Failure<ClientAction>> {
+        QBittorrentClient::set_first_last_piece_priority(self, hashes, enabled).await
+    }
+    async fn increase_priority(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<QueueAction>> {
+        QBittorrentClient::increase_priority(self, hashes).await
+    }
+    async fn decrease_priority(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<QueueAction>> {
+        QBittorrentClient::decrease_priority(self, hashes).await
+    }
+    async fn top_priority(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<QueueAction>> {
+        QBittorrentClient::top_priority(self, hashes).await
+    }
+    async fn bottom_priority(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<QueueAction>> {
+        QBittorrentClient::bottom_priority(self, hashes).await
+    }
+    async fn reorder_queue(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<Vec<String>>, Failure<QueueAction>> {
+        QBittorrentClient::reorder_queue(self, hashes).await
+    }
+    async fn get_torrent_peers(
+        &self,
+        hash: String,
+        rid: u64,
+    ) -> Result<Response<PeersUpdate>, Failure<PeerAction>> {
+        QBittorrentClient::get_torrent_peers(self, hash, rid).await
+    }
+    async fn add_peers(
+        &self,
+        hashes: Vec<String>,
+        peers: Vec<PeerAddr>,
+    ) -> Result<Response<bool>, Failure<PeerAction>> {
+        QBittorrentClient::add_peers(self, hashes, peers).await
+    }
+    async fn ban_peers(&self, peers: Vec<PeerAddr>) -> Result<Response<bool>, Failure<PeerAction>> {
+        QBittorrentClient::ban_peers(self, peers).await
+    }
+    async fn get_piece_states(
+        &self,
+        hash: String,
+    ) -> Result<Response<Vec<PieceState>>, Failure<PieceAction>> {
+        QBittorrentClient::get_piece_states(self, hash).await
+    }
+    async fn get_piece_hashes(
+        &self,
+        hash: String,
+    ) -> Result<Response<Vec<String>>, Failure<PieceAction>> {
+        QBittorrentClient::get_piece_hashes(self, hash).await
+    }
+    async fn get_webseeds(
+        &self,
+        hash: String,
+    ) -> Result<Response<Vec<WebSeed>>, Failure<WebSeedAction>> {
+        QBittorrentClient::get_webseeds(self, hash).await
+    }
+    async fn add_webseeds(
+        &self,
+        hash: String,
+        urls: Vec<String>,
+    ) -> Result<Response<bool>, Failure<WebSeedAction>> {
+        QBittorrentClient::add_webseeds(self, hash, urls).await
+    }
+    async fn edit_webseed(
+        &self,
+        hash: String,
+        old_url: String,
+        new_url: String,
+    ) -> Result<Response<bool>, Failure<WebSeedAction>> {
+        QBittorrentClient::edit_webseed(self, hash, old_url, new_url).await
+    }
+    async fn remove_webseeds(
+        &self,
+        hash: String,
+        urls: Vec<String>,
+    ) -> Result<Response<bool>, Failure<WebSeedAction>> {
+        QBittorrentClient::remove_webseeds(self, hash, urls).await
+    }
+    async fn get_transfer_info(&self) -> Result<Response<TransferInfo>, Failure<ClientAction>> {
+        QBittorrentClient::get_transfer_info(self).await
+    }
+    async fn set_global_download_limit(
+        &self,
+        limit: u64,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::set_global_download_limit(self, limit).await
+    }
+    async fn set_global_upload_limit(
+        &self,
+        limit: u64,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::set_global_upload_limit(self, limit).await
+    }
+    async fn get_speed_limits_mode(
+        &self,
+    ) -> Result<Response<SpeedLimitsMode>, Failure<ClientAction>> {
+        QBittorrentClient::get_speed_limits_mode(self).await
+    }
+    async fn toggle_speed_limits_mode(&self) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::toggle_speed_limits_mode(self).await
+    }
+    async fn set_speed_limits_mode(
+        &self,
+        mode: SpeedLimitsMode,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::set_speed_limits_mode(self, mode).await
+    }
+    async fn get_preferences(&self) -> Result<Response<Preferences>, Failure<ClientAction>> {
+        QBittorrentClient::get_preferences(self).await
+    }
+    async fn set_preferences(
+        &self,
+        patch: PreferencesPatch,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::set_preferences(self, patch).await
+    }
+    async fn get_build_info(&self) -> Result<Response<BuildInfo>, Failure<ClientAction>> {
+        QBittorrentClient::get_build_info(self).await
+    }
+    async fn get_default_save_path(&self) -> Result<Response<String>, Failure<ClientAction>> {
+        QBittorrentClient::get_default_save_path(self).await
+    }
+    async fn shutdown(&self) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::shutdown(self).await
+    }
+    async fn get_main_log(
+        &self,
+        filter: LogFilter,
+    ) -> Result<Response<Vec<LogEntry>>, Failure<ClientAction>> {
+        QBittorrentClient::get_main_log(self, filter).await
+    }
+    async fn get_peer_log(
+        &self,
+        last_known_id: Option<i64>,
+    ) -> Result<Response<Vec<PeerLogEntry>>, Failure<ClientAction>> {
+        QBittorrentClient::get_peer_log(self, last_known_id).await
+    }
+    async fn get_rss_items(
+        &self,
+        with_data: bool,
+    ) -> Result<Response<RssFolder>, Failure<RssAction>> {
+        QBittorrentClient::get_rss_items(self, with_data).await
+    }
+    async fn add_rss_folder(&self, path: String) -> Result<Response<bool>, Failure<RssAction>> {
+        QBittorrentClient::add_rss_folder(self, path).await
+    }
+    async fn add_rss_feed(
+        &self,
+        url: String,
+        path: Option<String>,
+    ) -> Result<Response<bool>, Failure<RssAction>> {
+        QBittorrentClient::add_rss_feed(self, url, path).await
+    }
+    async fn remove_rss_item(&self, path: String) -> Result<Response<bool>, Failure<RssAction>> {
+        QBittorrentClient::remove_rss_item(self, path).await
+    }
+    async fn move_rss_item(
+        &self,
+        path: String,
+        destination: String,
+    ) -> Result<Response<bool>, Failure<RssAction>> {
+        QBittorrentClient::move_rss_item(self, path, destination).await
+    }
+    async fn refresh_rss_item(&self, path: String) -> Result<Response<bool>, Failure<RssAction>> {
+        QBittorrentClient::refresh_rss_item(self, path).await
+    }
+    async fn mark_rss_as_read(
+        &self,
+        path: String,
+        article_id: Option<String>,
+    ) -> Result<Response<bool>, Failure<RssAction>> {
+        QBittorrentClient::mark_rss_as_read(self, path, article_id).await
+    }
+    async fn set_rss_rule(
+        &self,
+        name: String,
+        rule: AutoDownloadRule,
+    ) -> Result<Response<bool>, Failure<RssAction>> {
+        QBittorrentClient::set_rss_rule(self, name, rule).await
+    }
+    async fn rename_rss_rule(
+        &self,
+        name: String,
+        new_name: String,
+    ) -> Result<Response<bool>, Failure<RssAction>> {
+        QBittorrentClient::rename_rss_rule(self, name, new_name).await
+    }
+    async fn remove_rss_rule(&self, name: String) -> Result<Response<bool>, Failure<RssAction>> {
+        QBittorrentClient::remove_rss_rule(self, name).await
+    }
+    async fn get_rss_rules(
+        &self,
+    ) -> Result<Response<HashMap<String, AutoDownloadRule>>, Failure<RssAction>> {
+        QBittorrentClient::get_rss_rules(self).await
+    }
+    async fn get_rss_matching_articles(
+        &self,
+        name: String,
+    ) -> Result<Response<HashMap<String, Vec<String>>>, Failure<RssAction>> {
+        QBittorrentClient::get_rss_matching_articles(self, name).await
+    }
+    async fn add_torrent_urls(
+        &self,
+        options: AddTorrentOptions,
+        urls: Vec<String>,
+    ) -> Result<Response<bool>, Failure<AddTorrentAction>> {
+        QBittorrentClient::add_torrent_urls(self, options, urls).await
+    }
+    async fn start_search(
+        &self,
+        query: SearchQuery,
+    ) -> Result<Response<u64>, Failure<SearchAction>> {
+        QBittorrentClient::start_search(self, query).await
+    }
+    async fn get_search_status(
+        &self,
+        id: Option<u64>,
+    ) -> Result<Response<Vec<SearchStatus>>, Failure<SearchAction>> {
+        QBittorrentClient::get_search_status(self, id).await
+    }
+    async fn get_search_results(
+        &self,
+        id: u64,
+        offset: usize,
+        limit: Option<usize>,
+    ) -> Result<Response<SearchResults>, Failure<SearchAction>> {
+        QBittorrentClient::get_search_results(self, id, offset, limit).await
+    }
+    async fn stop_search(&self, id: u64) -> Result<Response<bool>, Failure<SearchAction>> {
+        QBittorrentClient::stop_search(self, id).await
+    }
+    async fn delete_search(&self, id: u64) -> Result<Response<bool>, Failure<SearchAction>> {
+        QBittorrentClient::delete_search(self, id).await
+    }
+    async fn get_search_plugins(
+        &self,
+    ) -> Result<Response<Vec<SearchPlugin>>, Failure<SearchAction>> {
+        QBittorrentClient::get_search_plugins(self).await
+    }
+    async fn install_search_plugin(
+        &self,
+        sources: Vec<String>,
+    ) -> Result<Response<bool>, Failure<SearchAction>> {
+        QBittorrentClient::install_search_plugin(self, sources).await
+    }
+    async fn uninstall_search_plugin(
+        &self,
+        names: Vec<String>,
+    ) -> Result<Response<bool>, Failure<SearchAction>> {
+        QBittorrentClient::uninstall_search_plugin(self, names).await
+    }
+    async fn enable_search_plugin(
+        &self,
+        names: Vec<String>,
+        enable: bool,
+    ) -> Result<Response<bool>, Failure<SearchAction>> {
+        QBittorrentClient::enable_search_plugin(self, names, enable).await
+    }
+    async fn update_search_plugins(&self) -> Result<Response<bool>, Failure<SearchAction>> {
+        QBittorrentClient::update_search_plugins(self).await
+    }
+    async fn add_torrent_creation_task(
+        &self,
+        task: TorrentCreationTask,
+    ) -> Result<Response<String>, Failure<TorrentCreatorAction>> {
+        QBittorrentClient::add_torrent_creation_task(self, task).await
+    }
+    async fn get_torrent_creation_status(
+        &self,
+        task_id: Option<String>,
+    ) -> Result<Response<Vec<TorrentCreationStatus>>, Failure<TorrentCreatorAction>> {
+        QBittorrentClient::get_torrent_creation_status(self, task_id).await
+    }
+    async fn get_torrent_creation_file(
+        &self,
+        task_id: String,
+    ) -> Result<Response<Vec<u8>>, Failure<TorrentCreatorAction>> {
+        QBittorrentClient::get_torrent_creation_file(self, task_id).await
+    }
+    async fn delete_torrent_creation_task(
+        &self,
+        task_id: String,
+    ) -> Result<Response<bool>, Failure<TorrentCreatorAction>> {
+        QBittorrentClient::delete_torrent_creation_task(self, task_id).await
+    }
+    async fn get_directory_content(
+        &self,
+        path: String,
+        mode: DirectoryContentMode,
+    ) -> Result<Response<Vec<DirectoryEntry>>, Failure<DirectoryAction>> {
+        QBittorrentClient::get_directory_content(self, path, mode).await
+    }
+    async fn login(&self) -> Result<Status, Failure<ClientAction>> {
+        QBittorrentClient::login(self).await
+    }
+    async fn ensure_login(&self) -> Result<(), Failure<ClientAction>> {
+        QBittorrentClient::ensure_login(self).await
+    }
+    async fn logout(&self) -> Result<Response<bool>, Failure<ClientAction>> {
+        QBittorrentClient::logout(self).await
+    }
 }
 
 /// Errors returned by [`QBittorrentClient`] request operations
@@ -197,4 +791,6 @@ pub enum ClientAction {
     ValidateResponse,
     #[error("login")]
     Login,
+    #[error("check endpoint is supported by server version")]
+    CheckSupport,
 }
@@ -1,12 +1,32 @@
 //! Trait abstraction for qBittorrent API operations.
 
 use async_trait::async_trait;
+use std::collections::HashMap;
 use std::path::PathBuf;
 
-use crate::Response;
 use crate::add_torrent::{AddTorrentAction, AddTorrentOptions};
+use crate::app::BuildInfo;
+use crate::categories::{Category, CategoryAction};
 use crate::client::ClientAction;
+use crate::directory::{DirectoryAction, DirectoryContentMode, DirectoryEntry};
 use crate::get_torrents::{FilterOptions, Torrent};
+use crate::limits::ShareLimits;
+use crate::location::LocationAction;
+use crate::logs::{LogEntry, LogFilter, PeerLogEntry};
+use crate::peers::PeerAddr;
+use crate::peers::{PeerAction, PeersUpdate};
+use crate::pieces::{PieceAction, PieceState};
+use crate::preferences::{Preferences, PreferencesPatch};
+use crate::queue::QueueAction;
+use crate::rename::RenameAction;
+use crate::rss::{RssAction, RssFolder};
+use crate::rss_rules::AutoDownloadRule;
+use crate::search::{SearchAction, SearchQuery, SearchResults, SearchStatus};
+use crate::search_plugins::SearchPlugin;
+use crate::torrent_creator::{TorrentCreationStatus, TorrentCreationTask, TorrentCreatorAction};
+use crate::transfer::{SpeedLimitsMode, TransferInfo};
+use crate::webseeds::{WebSeed, WebSeedAction};
+use crate::{Response, Status};
 use rogue_logging::Failure;
 
 /// Trait for qBittorrent API operations
@@ -34,4 +54,512 @@ pub trait QBittorrentClientTrait: Send + Sync {
         options: AddTorrentOptions,
         torrents: Vec<PathBuf>,
     ) -> Result<Response<bool>, Failure<AddTorrentAction>>;
+
+    /// Get all categories keyed by name
+    async fn get_categories(
+        &self,
+    ) -> Result<Response<HashMap<String, Category>>, Failure<ClientAction>>;
+
+    /// Create a new category
+    async fn create_category(
+        &self,
+        category: Category,
+    ) -> Result<Response<bool>, Failure<CategoryAction>>;
+
+    /// Edit the save path of an existing category
+    async fn edit_category(
+        &self,
+        category: Category,
+    ) -> Result<Response<bool>, Failure<CategoryAction>>;
+
+    /// Remove categories by name
+    async fn remove_categories(
+        &self,
+        names: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Set the category of torrents
+    async fn set_torrent_category(
+        &self,
+        hashes: Vec<String>,
+        name: String,
+    ) -> Result<Response<bool>, Failure<CategoryAction>>;
+
+    /// Get all tags
+    async fn get_tags(&self) -> Result<Response<Vec<String>>, Failure<ClientAction>>;
+
+    /// Create tags that are not assigned to any torrent
+    async fn create_tags(&self, tags: Vec<String>)
+    -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Delete tags and remove them from all torrents
+    async fn delete_tags(&self, tags: Vec<String>)
+    -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Add tags to torrents
+    async fn add_torrent_tags(
+        &self,
+        hashes: Vec<String>,
+        tags: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Remove tags from torrents
+    async fn remove_torrent_tags(
+        &self,
+        hashes: Vec<String>,
+        tags: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Replace the tags of torrents
+    async fn set_torrent_tags(
+        &self,
+        hashes: Vec<String>,
+        tags: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Move torrents to a new location
+    async fn set_location(
+        &self,
+        hashes: Vec<String>,
+        path: String,
+    ) -> Result<Response<bool>, Failure<LocationAction>>;
+
+    /// Set the save path of torrents
+    async fn set_save_path(
+        &self,
+        hashes: Vec<String>,
+        path: String,
+    ) -> Result<Response<bool>, Failure<LocationAction>>;
+
+    /// Set the download path of incomplete torrents
+    async fn set_download_path(
+        &self,
+        hashes: Vec<String>,
+        path: String,
+    ) -> Result<Response<bool>, Failure<LocationAction>>;
+
+    /// Recheck the data of torrents
+    async fn recheck_torrents(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Reannounce torrents to their trackers
+    async fn reannounce_torrents(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Rename a torrent
+    async fn rename_torrent(
+        &self,
+        hash: String,
+        name: String,
+    ) -> Result<Response<bool>, Failure<RenameAction>>;
+
+    /// Rename or move a file within a torrent
+    async fn rename_file(
+        &self,
+        hash: String,
+        old_path: String,
+        new_path: String,
+    ) -> Result<Response<bool>, Failure<RenameAction>>;
+
+    /// Rename or move a folder within a torrent
+    async fn rename_folder(
+        &self,
+        hash: String,
+        old_path: String,
+        new_path: String,
+    ) -> Result<Response<bool>, Failure<RenameAction>>;
+
+    /// Get the download speed limit (bytes/s) of torrents keyed by hash
+    async fn get_download_limits(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<HashMap<String, i64>>, Failure<ClientAction>>;
+
+    /// Set the download speed limit (bytes/s) of torrents
+    async fn set_download_limit(
+        &self,
+        hashes: Vec<String>,
+        limit: u64,
+    ) -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Get the upload speed limit (bytes/s) of torrents keyed by hash
+    async fn get_upload_limits(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<HashMap<String, i64>>, Failure<ClientAction>>;
+
+    /// Set the upload speed limit (bytes/s) of torrents
+    async fn set_upload_limit(
+        &self,
+        hashes: Vec<String>,
+        limit: u64,
+    ) -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Set the share ratio and seeding time limits of torrents
+    async fn set_share_limits(
+        &self,
+        hashes: Vec<String>,
+        limits: ShareLimits,
+    ) -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Enable or disable force start for torrents
+    async fn set_force_start(
+        &self,
+        hashes: Vec<String>,
+        enabled: bool,
+    ) -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Enable or disable super seeding for torrents
+    async fn set_super_seeding(
+        &self,
+        hashes: Vec<String>,
+        enabled: bool,
+    ) -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Enable or disable Automatic Torrent Management for torrents
+    async fn set_auto_management(
+        &self,
+        hashes: Vec<String>,
+        enabled: bool,
+    ) -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Toggle sequential download for torrents
+    async fn toggle_sequential_download(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Toggle first and last piece priority for torrents
+    async fn toggle_first_last_piece_priority(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Enable or disable sequential download for torrents
+    async fn set_sequential_download(
+        &self,
+        hashes: Vec<String>,
+        enabled: bool,
+    ) -> Result<Response<Vec<String>>, Failure<ClientAction>>;
+
+    /// Enable or disable first and last piece priority for torrents
+    async fn set_first_last_piece_priority(
+        &self,
+        hashes: Vec<String>,
+        enabled: bool,
+    ) -> Result<Response<Vec<String>>, Failure<ClientAction>>;
+
+    /// Move torrents one position up the queue
+    async fn increase_priority(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<QueueAction>>;
+
+    /// Move torrents one position down the queue
+    async fn decrease_priority(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<QueueAction>>;
+
+    /// Move torrents to the top of the queue
+    async fn top_priority(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<QueueAction>>;
+
+    /// Move torrents to the bottom of the queue
+    async fn bottom_priority(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<QueueAction>>;
+
+    /// Rewrite the queue so that `hashes` are at the top in the given order
+    async fn reorder_queue(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<Vec<String>>, Failure<QueueAction>>;
+
+    /// Get the peer changes of a torrent since response id `rid`
+    async fn get_torrent_peers(
+        &self,
+        hash: String,
+        rid: u64,
+    ) -> Result<Response<PeersUpdate>, Failure<PeerAction>>;
+
+    /// Add peers to torrents
+    async fn add_peers(
+        &self,
+        hashes: Vec<String>,
+        peers: Vec<PeerAddr>,
+    ) -> Result<Response<bool>, Failure<PeerAction>>;
+
+    /// Ban peers from connecting to any torrent
+    async fn ban_peers(&self, peers: Vec<PeerAddr>) -> Result<Response<bool>, Failure<PeerAction>>;
+
+    /// Get the download state of each piece of a torrent
+    async fn get_piece_states(
+        &self,
+        hash: String,
+    ) -> Result<Response<Vec<PieceState>>, Failure<PieceAction>>;
+
+    /// Get the SHA-1 hash of each piece of a torrent as lowercase hex
+    async fn get_piece_hashes(
+        &self,
+        hash: String,
+    ) -> Result<Response<Vec<String>>, Failure<PieceAction>>;
+
+    /// Get the web seeds of a torrent
+    async fn get_webseeds(
+        &self,
+        hash: String,
+    ) -> Result<Response<Vec<WebSeed>>, Failure<WebSeedAction>>;
+
+    /// Add web seeds to a torrent
+    async fn add_webseeds(
+        &self,
+        hash: String,
+        urls: Vec<String>,
+    ) -> Result<Response<bool>, Failure<WebSeedAction>>;
+
+    /// Replace the URL of a web seed of a torrent
+    async fn edit_webseed(
+        &self,
+        hash: String,
+        old_url: String,
+        new_url: String,
+    ) -> Result<Response<bool>, Failure<WebSeedAction>>;
+
+    /// Remove web seeds from a torrent
+    async fn remove_webseeds(
+        &self,
+        hash: String,
+        urls: Vec<String>,
+    ) -> Result<Response<bool>, Failure<WebSeedAction>>;
+
+    /// Get global transfer speeds, session totals and connection status
+    async fn get_transfer_info(&self) -> Result<Response<TransferInfo>, Failure<ClientAction>>;
+
+    /// Set the global download speed limit (bytes/s)
+    async fn set_global_download_limit(
+        &self,
+        limit: u64,
+    ) -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Set the global upload speed limit (bytes/s)
+    async fn set_global_upload_limit(
+        &self,
+        limit: u64,
+    ) -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Get whether the global or alternative speed limits are in use
+    async fn get_speed_limits_mode(
+        &self,
+    ) -> Result<Response<SpeedLimitsMode>, Failure<ClientAction>>;
+
+    /// Toggle between the global and alternative speed limits
+    async fn toggle_speed_limits_mode(&self) -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Use the global or alternative speed limits
+    async fn set_speed_limits_mode(
+        &self,
+        mode: SpeedLimitsMode,
+    ) -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Get the application preferences
+    async fn get_preferences(&self) -> Result<Response<Preferences>, Failure<ClientAction>>;
+
+    /// Set application preferences
+    async fn set_preferences(
+        &self,
+        patch: PreferencesPatch,
+    ) -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Get the versions of the libraries qBittorrent was built with
+    async fn get_build_info(&self) -> Result<Response<BuildInfo>, Failure<ClientAction>>;
+
+    /// Get the default save path for torrents
+    async fn get_default_save_path(&self) -> Result<Response<String>, Failure<ClientAction>>;
+
+    /// Shut down the qBittorrent application
+    async fn shutdown(&self) -> Result<Response<bool>, Failure<ClientAction>>;
+
+    /// Get main log entries matching `filter`
+    async fn get_main_log(
+        &self,
+        filter: LogFilter,
+    ) -> Result<Response<Vec<LogEntry>>, Failure<ClientAction>>;
+
+    /// Get peer log entries newer than `last_known_id`
+    async fn get_peer_log(
+        &self,
+        last_known_id: Option<i64>,
+    ) -> Result<Response<Vec<PeerLogEntry>>, Failure<ClientAction>>;
+
+    /// Get the tree of RSS folders and feeds
+    async fn get_rss_items(
+        &self,
+        with_data: bool,
+    ) -> Result<Response<RssFolder>, Failure<RssAction>>;
+
+    /// Add an RSS folder
+    async fn add_rss_folder(&self, path: String) -> Result<Response<bool>, Failure<RssAction>>;
+
+    /// Add an RSS feed
+    async fn add_rss_feed(
+        &self,
+        url: String,
+        path: Option<String>,
+    ) -> Result<Response<bool>, Failure<RssAction>>;
+
+    /// Remove an RSS folder or feed
+    async fn remove_rss_item(&self, path: String) -> Result<Response<bool>, Failure<RssAction>>;
+
+    /// Move or rename an RSS folder or feed
+    async fn move_rss_item(
+        &self,
+        path: String,
+        destination: String,
+    ) -> Result<Response<bool>, Failure<RssAction>>;
+
+    /// Refresh an RSS feed, or every feed in an RSS folder
+    async fn refresh_rss_item(&self, path: String) -> Result<Response<bool>, Failure<RssAction>>;
+
+    /// Mark an article, or every article of an RSS feed or folder, as read
+    async fn mark_rss_as_read(
+        &self,
+        path: String,
+        article_id: Option<String>,
+    ) -> Result<Response<bool>, Failure<RssAction>>;
+
+    /// Create or replace an RSS auto-download rule
+    async fn set_rss_rule(
+        &self,
+        name: String,
+        rule: AutoDownloadRule,
+    ) -> Result<Response<bool>, Failure<RssAction>>;
+
+    /// Rename an RSS auto-download rule
+    async fn rename_rss_rule(
+        &self,
+        name: String,
+        new_name: String,
+    ) -> Result<Response<bool>, Failure<RssAction>>;
+
+    /// Remove an RSS auto-download rule
+    async fn remove_rss_rule(&self, name: String) -> Result<Response<bool>, Failure<RssAction>>;
+
+    /// Get all RSS auto-download rules keyed by name
+    async fn get_rss_rules(
+        &self,
+    ) -> Result<Response<HashMap<String, AutoDownloadRule>>, Failure<RssAction>>;
+
+    /// Get the titles of articles matching an RSS auto-download rule keyed by feed name
+    async fn get_rss_matching_articles(
+        &self,
+        name: String,
+    ) -> Result<Response<HashMap<String, Vec<String>>>, Failure<RssAction>>;
+
+    /// Add torrents from URLs or magnet links
+    async fn add_torrent_urls(
+        &self,
+        options: AddTorrentOptions,
+        urls: Vec<String>,
+    ) -> Result<Response<bool>, Failure<AddTorrentAction>>;
+
+    /// Start a search job
+    async fn start_search(
+        &self,
+        query: SearchQuery,
+    ) -> Result<Response<u64>, Failure<SearchAction>>;
+
+    /// Get the status of a search job, or of every search job if `id` is `None`
+    async fn get_search_status(
+        &self,
+        id: Option<u64>,
+    ) -> Result<Response<Vec<SearchStatus>>, Failure<SearchAction>>;
+
+    /// Get results of a search job starting at `offset`
+    async fn get_search_results(
+        &self,
+        id: u64,
+        offset: usize,
+        limit: Option<usize>,
+    ) -> Result<Response<SearchResults>, Failure<SearchAction>>;
+
+    /// Stop a running search job
+    async fn stop_search(&self, id: u64) -> Result<Response<bool>, Failure<SearchAction>>;
+
+    /// Stop a search job if it is running and delete it with its results
+    async fn delete_search(&self, id: u64) -> Result<Response<bool>, Failure<SearchAction>>;
+
+    /// Get the installed search plugins
+    async fn get_search_plugins(
+        &self,
+    ) -> Result<Response<Vec<SearchPlugin>>, Failure<SearchAction>>;
+
+    /// Install search plugins from URLs or paths on the server
+    async fn install_search_plugin(
+        &self,
+        sources: Vec<String>,
+    ) -> Result<Response<bool>, Failure<SearchAction>>;
+
+    /// Uninstall search plugins by name
+    async fn uninstall_search_plugin(
+        &self,
+        names: Vec<String>,
+    ) -> Result<Response<bool>, Failure<SearchAction>>;
+
+    /// Enable or disable search plugins by name
+    async fn enable_search_plugin(
+        &self,
+        names: Vec<String>,
+        enable: bool,
+    ) -> Result<Response<bool>, Failure<SearchAction>>;
+
+    /// Update every installed search plugin to its latest version
+    async fn update_search_plugins(&self) -> Result<Response<bool>, Failure<SearchAction>>;
+
+    /// Queue a task to create a torrent from a file or directory on the server
+    async fn add_torrent_creation_task(
+        &self,
+        task: TorrentCreationTask,
+    ) -> Result<Response<String>, Failure<TorrentCreatorAction>>;
+
+    /// Get the status of a torrent creation task, or of every task if `task_id` is `None`
+    async fn get_torrent_creation_status(
+        &self,
+        task_id: Option<String>,
+    ) -> Result<Response<Vec<TorrentCreationStatus>>, Failure<TorrentCreatorAction>>;
+
+    /// Get the `.torrent` file created by a finished task
+    async fn get_torrent_creation_file(
+        &self,
+        task_id: String,
+    ) -> Result<Response<Vec<u8>>, Failure<TorrentCreatorAction>>;
+
+    /// Delete a torrent creation task, stopping it if it is running
+    async fn delete_torrent_creation_task(
+        &self,
+        task_id: String,
+    ) -> Result<Response<bool>, Failure<TorrentCreatorAction>>;
+
+    /// Get the directories, files or both within a directory on the server
+    async fn get_directory_content(
+        &self,
+        path: String,
+        mode: DirectoryContentMode,
+    ) -> Result<Response<Vec<DirectoryEntry>>, Failure<DirectoryAction>>;
+
+    /// Login and get a session cookie
+    async fn login(&self) -> Result<Status, Failure<ClientAction>>;
+
+    /// Login if no valid session cookie exists
+    async fn ensure_login(&self) -> Result<(), Failure<ClientAction>>;
+
+    /// Logout and clear the session cookie, including from the session store
+    async fn logout(&self) -> Result<Response<bool>, Failure<ClientAction>>;
 }
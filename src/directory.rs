@@ -0,0 +1,262 @@
+//! Browsing directories on the server and validating save paths.
+
+use crate::add_torrent::{AddTorrentOptions, is_absolute_path};
+use crate::app::Version;
+use crate::client::deserialize_response;
+use crate::{QBittorrentClient, Response};
+use reqwest::Method;
+use rogue_logging::Failure;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// First qBittorrent version with `/app/getDirectoryContent`.
+const DIRECTORY_CONTENT_VERSION: Version = Version::new(5, 0, 0);
+
+impl QBittorrentClient {
+    /// Get the directories, files or both within a directory on the server
+    ///
+    /// `path` must be absolute. Entries are returned as absolute paths.
+    ///
+    /// With [`DirectoryContentMode::All`] directories and files are requested separately so the
+    /// kind of each entry is known.
+    ///
+    /// Returns [`DirectoryAction::InvalidPath`] if `path` is not absolute, or
+    /// [`DirectoryAction::DirectoryNotFound`] if it does not exist.
+    ///
+    /// Added in v5.0. Returns [`DirectoryAction::Request`] wrapping
+    /// [`ClientAction::CheckSupport`](crate::ClientAction::CheckSupport) on older servers.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-directory-content>
+    pub async fn get_directory_content(
+        &self,
+        path: String,
+        mode: DirectoryContentMode,
+    ) -> Result<Response<Vec<DirectoryEntry>>, Failure<DirectoryAction>> {
+        let kinds = match mode {
+            DirectoryContentMode::Directories => vec![DirectoryEntryKind::Directory],
+            DirectoryContentMode::Files => vec![DirectoryEntryKind::File],
+            DirectoryContentMode::All => {
+                vec![DirectoryEntryKind::Directory, DirectoryEntryKind::File]
+            }
+        };
+        let mut response = Response {
+            status_code: None,
+            result: Some(Vec::new()),
+        };
+        for kind in kinds {
+            let paths = self.get_directory_paths(&path, kind).await?;
+            response.status_code = paths.status_code;
+            match (&mut response.result, paths.result) {
+                (Some(entries), Some(paths)) => {
+                    entries.extend(paths.into_iter().map(|path| DirectoryEntry { path, kind }));
+                }
+                _ => response.result = None,
+            }
+        }
+        Ok(response)
+    }
+
+    /// Check the save path of `options` is an existing directory on the server
+    ///
+    /// Call before [`QBittorrentClient::add_torrents`] to fail early instead of the server
+    /// creating the directory. An unset save path is valid as the server's default is used.
+    ///
+    /// Returns [`DirectoryAction::InvalidPath`] if the save path is relative, resolve it first with
+    /// [`AddTorrentOptions::resolve_save_path`].
+    ///
+    /// Returns [`DirectoryAction::DirectoryNotFound`] if the save path does not exist.
+    ///
+    /// Added in v5.0. Returns [`DirectoryAction::Request`] wrapping
+    /// [`ClientAction::CheckSupport`](crate::ClientAction::CheckSupport) on older servers.
+    pub async fn validate_save_path(
+        &self,
+        options: &AddTorrentOptions,
+    ) -> Result<(), Failure<DirectoryAction>> {
+        let Some(save_path) = &options.save_path else {
+            return Ok(());
+        };
+        check_absolute(save_path)?;
+        self.get_directory_paths(save_path, DirectoryEntryKind::Directory)
+            .await?
+            .get_result("get_directory_content")
+            .map_err(Failure::wrap(DirectoryAction::Request))?;
+        Ok(())
+    }
+
+    async fn get_directory_paths(
+        &self,
+        path: &str,
+        kind: DirectoryEntryKind,
+    ) -> Result<Response<Vec<String>>, Failure<DirectoryAction>> {
+        let method = Method::GET;
+        let endpoint = "/app/getDirectoryContent";
+        self.require_version(DIRECTORY_CONTENT_VERSION, endpoint)
+            .await
+            .map_err(Failure::wrap(DirectoryAction::Request))?;
+        check_absolute(path)?;
+        let query = DirectoryQuery {
+            path,
+            mode: match kind {
+                DirectoryEntryKind::Directory => "dirs",
+                DirectoryEntryKind::File => "files",
+            },
+        };
+        let response = self
+            .request_with_login(method.clone(), endpoint, &query)
+            .await
+            .map_err(Failure::wrap(DirectoryAction::Request))?;
+        match response.status().as_u16() {
+            400 => Err(
+                directory_failure(DirectoryAction::InvalidPath, endpoint, 400).with("path", path),
+            ),
+            404 => Err(
+                directory_failure(DirectoryAction::DirectoryNotFound, endpoint, 404)
+                    .with("path", path),
+            ),
+            _ => deserialize_response::<Vec<String>>(&method, endpoint, response)
+                .await
+                .map_err(Failure::wrap(DirectoryAction::Request)),
+        }
+    }
+}
+
+/// Fail with [`DirectoryAction::InvalidPath`] if `path` is not absolute.
+fn check_absolute(path: &str) -> Result<(), Failure<DirectoryAction>> {
+    if is_absolute_path(path) {
+        Ok(())
+    } else {
+        Err(Failure::from_action(DirectoryAction::InvalidPath)
+            .with("path", path)
+            .with("reason", "path is not absolute"))
+    }
+}
+
+fn directory_failure(
+    action: DirectoryAction,
+    endpoint: &str,
+    status_code: u16,
+) -> Failure<DirectoryAction> {
+    Failure::from_action(action)
+        .with("endpoint", endpoint)
+        .with("status_code", status_code.to_string())
+}
+
+#[derive(Serialize)]
+struct DirectoryQuery<'a> {
+    #[serde(rename = "dirPath")]
+    path: &'a str,
+    mode: &'static str,
+}
+
+/// Entries to get with [`QBittorrentClient::get_directory_content`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DirectoryContentMode {
+    /// Only directories.
+    Directories,
+    /// Only files.
+    Files,
+    /// Directories and files.
+    #[default]
+    All,
+}
+
+/// Whether a directory entry is a directory or a file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum DirectoryEntryKind {
+    /// A directory.
+    Directory,
+    /// A file.
+    File,
+}
+
+/// An entry of a directory on the server.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DirectoryEntry {
+    /// Absolute path on the server.
+    pub path: String,
+
+    /// Whether the entry is a directory or a file.
+    pub kind: DirectoryEntryKind,
+}
+
+/// Errors returned by directory operations
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Error)]
+pub enum DirectoryAction {
+    #[error("send request")]
+    Request,
+    #[error("validate directory path")]
+    InvalidPath,
+    #[error("find directory")]
+    DirectoryNotFound,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QBittorrentClientOptions;
+    use crate::tests::init_logger;
+    use log::trace;
+    use rogue_config::{OptionsProvider, YamlOptionsProvider};
+    use std::error::Error;
+
+    #[tokio::test]
+    #[ignore = "integration test requiring API credentials"]
+    async fn get_directory_content() -> Result<(), Box<dyn Error>> {
+        // Arrange
+        init_logger();
+        let options: QBittorrentClientOptions =
+            YamlOptionsProvider::get().map_err(|e| e.to_string())?;
+        let client = QBittorrentClient::from_options(options);
+        let path = client
+            .get_default_save_path()
+            .await?
+            .get_result("get_default_save_path")?;
+
+        // Act
+        let response = client
+            .get_directory_content(path, DirectoryContentMode::All)
+            .await?;
+        trace!("{}", response.to_json_pretty());
+
+        // Assert
+        let _result = response.get_result("get_directory_content")?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "integration test requiring API credentials"]
+    async fn validate_save_path_not_found() -> Result<(), Box<dyn Error>> {
+        // Arrange
+        init_logger();
+        let options: QBittorrentClientOptions =
+            YamlOptionsProvider::get().map_err(|e| e.to_string())?;
+        let client = QBittorrentClient::from_options(options);
+        let options = AddTorrentOptions {
+            save_path: Some("/qbittorrent-api/does/not/exist".to_owned()),
+            ..AddTorrentOptions::default()
+        };
+
+        // Act
+        let error = client
+            .validate_save_path(&options)
+            .await
+            .expect_err("save path should not be found");
+
+        // Assert
+        assert_eq!(error.action(), &DirectoryAction::DirectoryNotFound);
+        Ok(())
+    }
+
+    #[test]
+    fn check_absolute_accepts_unix_and_windows_paths() {
+        assert!(check_absolute("/downloads/music").is_ok());
+        assert!(check_absolute("D:\\Downloads").is_ok());
+    }
+
+    #[test]
+    fn check_absolute_rejects_relative_paths() {
+        let error = check_absolute("music").expect_err("relative path should be invalid");
+        assert_eq!(error.action(), &DirectoryAction::InvalidPath);
+    }
+}
@@ -1,14 +1,16 @@
 //! Factory for constructing a rate-limited [`QBittorrentClient`].
 
 use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 use std::time::Duration;
 
 use crate::QBittorrentClient;
 use crate::QBittorrentClientOptions;
+use crate::session_store::{FileSessionStore, SessionStore, load_session};
 use reqwest::cookie::Jar;
 use reqwest::header::{HeaderMap, HeaderValue};
 use reqwest::{ClientBuilder, header};
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, OnceCell};
 
 /// Default number of requests allowed per duration.
 const DEFAULT_RATE_COUNT: usize = 10;
@@ -23,12 +25,35 @@ pub struct QBittorrentClientFactory {
 
 impl QBittorrentClientFactory {
     /// Create a new [`QBittorrentClient`] with rate limiting applied.
+    ///
+    /// The session cookie is persisted if [`QBittorrentClientOptions::session_file`] is set.
     #[must_use]
+    pub fn create(&self) -> QBittorrentClient {
+        let session_store = self
+            .options
+            .session_file
+            .clone()
+            .map(|path| -> Arc<dyn SessionStore> { Arc::new(FileSessionStore::new(path)) });
+        self.create_with_store(session_store)
+    }
+
+    /// Create a new [`QBittorrentClient`] with rate limiting applied that persists the
+    /// session cookie in `session_store`.
+    ///
+    /// Overrides [`QBittorrentClientOptions::session_file`].
+    #[must_use]
+    pub fn create_with_session_store(
+        &self,
+        session_store: Arc<dyn SessionStore>,
+    ) -> QBittorrentClient {
+        self.create_with_store(Some(session_store))
+    }
+
     #[expect(
         clippy::as_conversions,
         reason = "usize to u64 is safe for small config values"
     )]
-    pub fn create(&self) -> QBittorrentClient {
+    fn create_with_store(&self, session_store: Option<Arc<dyn SessionStore>>) -> QBittorrentClient {
         let rate_count = self.options.rate_limit_count.unwrap_or(DEFAULT_RATE_COUNT) as u64;
         let rate_duration = self
             .options
@@ -36,6 +61,9 @@ impl QBittorrentClientFactory {
             .unwrap_or(DEFAULT_RATE_DURATION) as u64;
         let rate_duration = Duration::from_secs(rate_duration);
         let cookies = Arc::new(Jar::default());
+        let unverified_session = session_store
+            .as_ref()
+            .is_some_and(|store| load_session(store.as_ref(), &self.options.host, &cookies));
         let client = ClientBuilder::new()
             .default_headers(self.get_headers())
             .cookie_provider(cookies.clone())
@@ -50,6 +78,9 @@ impl QBittorrentClientFactory {
             username: self.options.username.clone(),
             password: self.options.password.clone(),
             client: Mutex::new(client),
+            version: OnceCell::new(),
+            session_store,
+            unverified_session: AtomicBool::new(unverified_session),
         }
     }
 
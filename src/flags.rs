@@ -0,0 +1,251 @@
+//! Per-torrent flags: force start, super seeding, automatic management, sequential download
+//! and first/last piece priority.
+
+use crate::client::{ClientAction, join_hashes, success_response};
+use crate::get_torrents::{FilterOptions, Torrent};
+use crate::{QBittorrentClient, Response};
+use reqwest::Method;
+use rogue_logging::Failure;
+
+impl QBittorrentClient {
+    /// Enable or disable force start for torrents
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#set-force-start>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#set-force-start>
+    pub async fn set_force_start(
+        &self,
+        hashes: Vec<String>,
+        enabled: bool,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        self.set_flag("/torrents/setForceStart", "value", &hashes, enabled)
+            .await
+    }
+
+    /// Enable or disable super seeding for torrents
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#set-super-seeding>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#set-super-seeding>
+    pub async fn set_super_seeding(
+        &self,
+        hashes: Vec<String>,
+        enabled: bool,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        self.set_flag("/torrents/setSuperSeeding", "value", &hashes, enabled)
+            .await
+    }
+
+    /// Enable or disable Automatic Torrent Management for torrents
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#set-automatic-torrent-management>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#set-automatic-torrent-management>
+    pub async fn set_auto_management(
+        &self,
+        hashes: Vec<String>,
+        enabled: bool,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        self.set_flag("/torrents/setAutoManagement", "enable", &hashes, enabled)
+            .await
+    }
+
+    /// Toggle sequential download for torrents
+    ///
+    /// Prefer [`set_sequential_download`](Self::set_sequential_download) to set a known state.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#toggle-sequential-download>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#toggle-sequential-download>
+    pub async fn toggle_sequential_download(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        self.toggle("/torrents/toggleSequentialDownload", &hashes)
+            .await
+    }
+
+    /// Toggle first and last piece priority for torrents
+    ///
+    /// Prefer [`set_first_last_piece_priority`](Self::set_first_last_piece_priority)
+    /// to set a known state.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#set-firstlast-piece-priority>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#set-firstlast-piece-priority>
+    pub async fn toggle_first_last_piece_priority(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        self.toggle("/torrents/toggleFirstLastPiecePrio", &hashes)
+            .await
+    }
+
+    /// Enable or disable sequential download for torrents
+    ///
+    /// The API can only toggle this flag, so only torrents whose
+    /// [`seq_dl`](Torrent::seq_dl) differs from `enabled` are toggled.
+    ///
+    /// Returns the hashes of the toggled torrents. No request is sent if `hashes` is empty.
+    pub async fn set_sequential_download(
+        &self,
+        hashes: Vec<String>,
+        enabled: bool,
+    ) -> Result<Response<Vec<String>>, Failure<ClientAction>> {
+        self.toggle_to(
+            "/torrents/toggleSequentialDownload",
+            hashes,
+            enabled,
+            |torrent| torrent.seq_dl,
+        )
+        .await
+    }
+
+    /// Enable or disable first and last piece priority for torrents
+    ///
+    /// The API can only toggle this flag, so only torrents whose
+    /// [`f_l_piece_prio`](Torrent::f_l_piece_prio) differs from `enabled` are toggled.
+    ///
+    /// Returns the hashes of the toggled torrents. No request is sent if `hashes` is empty.
+    pub async fn set_first_last_piece_priority(
+        &self,
+        hashes: Vec<String>,
+        enabled: bool,
+    ) -> Result<Response<Vec<String>>, Failure<ClientAction>> {
+        self.toggle_to(
+            "/torrents/toggleFirstLastPiecePrio",
+            hashes,
+            enabled,
+            |torrent| torrent.f_l_piece_prio,
+        )
+        .await
+    }
+
+    async fn set_flag(
+        &self,
+        endpoint: &str,
+        key: &str,
+        hashes: &[String],
+        enabled: bool,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        let data = vec![("hashes", join_hashes(hashes)), (key, enabled.to_string())];
+        let response = self
+            .request_with_login(Method::POST, endpoint, &data)
+            .await?;
+        Ok(success_response(&response))
+    }
+
+    async fn toggle(
+        &self,
+        endpoint: &str,
+        hashes: &[String],
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        let data = vec![("hashes", join_hashes(hashes))];
+        let response = self
+            .request_with_login(Method::POST, endpoint, &data)
+            .await?;
+        Ok(success_response(&response))
+    }
+
+    async fn toggle_to(
+        &self,
+        endpoint: &str,
+        hashes: Vec<String>,
+        enabled: bool,
+        get_flag: fn(&Torrent) -> bool,
+    ) -> Result<Response<Vec<String>>, Failure<ClientAction>> {
+        if hashes.is_empty() {
+            // An empty hashes filter matches every torrent.
+            return Ok(Response {
+                status_code: Some(200),
+                result: Some(Vec::new()),
+            });
+        }
+        let filters = FilterOptions {
+            hashes: Some(join_hashes(&hashes)),
+            ..FilterOptions::default()
+        };
+        let response = self.get_torrents(filters).await?;
+        let status_code = response.status_code;
+        let torrents = response.get_result("get_torrents")?;
+        let toggle = get_hashes_to_toggle(&torrents, enabled, get_flag);
+        if toggle.is_empty() {
+            return Ok(Response {
+                status_code,
+                result: Some(toggle),
+            });
+        }
+        let response = self.toggle(endpoint, &toggle).await?;
+        Ok(Response {
+            status_code: response.status_code,
+            result: Some(toggle),
+        })
+    }
+}
+
+/// Hashes of the torrents whose flag differs from the desired state.
+fn get_hashes_to_toggle(
+    torrents: &[Torrent],
+    enabled: bool,
+    get_flag: fn(&Torrent) -> bool,
+) -> Vec<String> {
+    torrents
+        .iter()
+        .filter(|torrent| get_flag(torrent) != enabled)
+        .map(|torrent| torrent.hash.clone())
+        .collect()
+}
+
+#[cfg(test)]
+#[expect(clippy::indexing_slicing, reason = "indexing fixture torrents")]
+mod tests {
+    use super::*;
+    use crate::QBittorrentClientOptions;
+
+    const FIXTURE_V5: &str = include_str!("tests/fixtures/torrents_info_v5.json");
+
+    fn fixture_torrents() -> Vec<Torrent> {
+        let mut torrents: Vec<Torrent> =
+            serde_json::from_str(FIXTURE_V5).expect("v5 fixture should deserialize");
+        torrents[0].seq_dl = true;
+        torrents[1].seq_dl = false;
+        torrents
+    }
+
+    #[test]
+    fn toggle_only_torrents_to_enable() {
+        let torrents = fixture_torrents();
+        let hashes = get_hashes_to_toggle(&torrents, true, |torrent| torrent.seq_dl);
+        assert_eq!(hashes, vec![torrents[1].hash.clone()]);
+    }
+
+    #[test]
+    fn toggle_only_torrents_to_disable() {
+        let torrents = fixture_torrents();
+        let hashes = get_hashes_to_toggle(&torrents, false, |torrent| torrent.seq_dl);
+        assert_eq!(hashes, vec![torrents[0].hash.clone()]);
+    }
+
+    #[test]
+    fn toggle_none_when_all_match() {
+        let mut torrents = fixture_torrents();
+        for torrent in &mut torrents {
+            torrent.f_l_piece_prio = true;
+        }
+        let hashes = get_hashes_to_toggle(&torrents, true, |torrent| torrent.f_l_piece_prio);
+        assert!(hashes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn toggle_to_empty_hashes_sends_no_request() {
+        let client = QBittorrentClient::from_options(QBittorrentClientOptions {
+            host: "http://127.0.0.1:9".to_owned(),
+            ..QBittorrentClientOptions::default()
+        });
+        let response = client
+            .set_sequential_download(Vec::new(), true)
+            .await
+            .expect("empty hashes should not send a request");
+        assert_eq!(response.result, Some(Vec::new()));
+    }
+}
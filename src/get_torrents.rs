@@ -3,6 +3,7 @@
 use crate::QBittorrentClient;
 use crate::Response;
 use crate::client::{ClientAction, deserialize_response};
+use crate::tags::split_tags;
 use reqwest::Method;
 use rogue_logging::Failure;
 use serde::{Deserialize, Serialize};
@@ -226,15 +227,17 @@ pub struct Torrent {
 
     /// Per-torrent share ratio limit set by the user.
     ///
-    /// `-1` uses the global limit, `-2` means no limit.
+    /// `-2` uses the global limit, `-1` means no limit.
+    /// See [`ratio_share_limit`](Self::ratio_share_limit) for a typed value.
     pub ratio_limit: f64,
 
     /// Path where this torrent's data is stored.
     pub save_path: String,
 
-    /// Per-torrent seeding time limit in seconds set by the user.
+    /// Per-torrent seeding time limit in minutes set by the user.
     ///
-    /// `-1` uses the global limit, `-2` means no limit.
+    /// `-2` uses the global limit, `-1` means no limit.
+    /// See [`seeding_time_share_limit`](Self::seeding_time_share_limit) for a typed value.
     pub seeding_time_limit: i64,
 
     /// Time (Unix Epoch) when this torrent was last seen complete.
@@ -301,9 +304,11 @@ pub struct Torrent {
     #[serde(default)]
     pub max_inactive_seeding_time: Option<i64>,
 
-    /// Per-torrent inactive seeding time limit in seconds.
+    /// Per-torrent inactive seeding time limit in minutes.
     ///
-    /// `-1` uses the global limit, `-2` means no limit.
+    /// `-2` uses the global limit, `-1` means no limit.
+    /// See [`inactive_seeding_time_share_limit`](Self::inactive_seeding_time_share_limit)
+    /// for a typed value.
     ///
     /// Added in v4.6
     #[serde(default)]
@@ -376,6 +381,16 @@ pub struct Torrent {
     pub root_path: Option<String>,
 }
 
+impl Torrent {
+    /// Tags of the torrent parsed from the comma-concatenated [`tags`](Self::tags).
+    ///
+    /// Surrounding whitespace is trimmed and empty entries are skipped.
+    #[must_use]
+    pub fn tag_list(&self) -> Vec<String> {
+        split_tags(&self.tags)
+    }
+}
+
 #[cfg(feature = "mock")]
 impl Torrent {
     /// Create a mock `Torrent` for testing
@@ -597,6 +612,14 @@ mod tests {
         assert_yaml_snapshot!(torrents);
     }
 
+    #[test]
+    fn tag_list_from_v5_fixture() {
+        let torrents: Vec<Torrent> =
+            serde_json::from_str(FIXTURE_V5).expect("v5 fixture should deserialize");
+        assert!(torrents[0].tag_list().is_empty());
+        assert_eq!(torrents[1].tag_list(), vec!["new", "priority"]);
+    }
+
     #[test]
     fn serialize_filter_options() {
         let filters = FilterOptions {
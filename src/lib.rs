@@ -6,20 +6,47 @@
 pub use client::*;
 pub use client_trait::*;
 pub use factory::*;
+pub use login::*;
 pub use options::*;
 pub use response::*;
 pub use status::*;
 
 pub mod add_torrent;
+pub mod app;
+pub mod categories;
 mod client;
 mod client_trait;
+pub mod directory;
 mod factory;
+pub mod flags;
 pub mod get_torrents;
+pub mod limits;
+pub mod location;
 mod login;
+pub mod logs;
 #[cfg(feature = "mock")]
 pub mod mock;
 mod options;
+pub mod peer_ban;
+pub mod peers;
+pub mod pieces;
+pub mod preferences;
+pub mod queue;
+pub mod recheck;
+pub mod reconcile;
+pub mod rename;
 mod response;
+pub mod rss;
+pub mod rss_rule_matcher;
+pub mod rss_rules;
+pub mod search;
+pub mod search_plugins;
+pub mod session_store;
 mod status;
+pub mod tags;
 #[cfg(test)]
 mod tests;
+pub mod torrent_creator;
+pub mod transfer;
+pub mod wait;
+pub mod webseeds;
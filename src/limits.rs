@@ -0,0 +1,294 @@
+//! Per-torrent speed and share limits.
+
+use std::collections::HashMap;
+
+use crate::client::{ClientAction, deserialize_response, join_hashes, success_response};
+use crate::get_torrents::Torrent;
+use crate::{QBittorrentClient, Response};
+use reqwest::Method;
+use rogue_logging::Failure;
+use serde::{Deserialize, Serialize};
+
+/// Ratio limit sentinel for "use the global limit".
+const USE_GLOBAL_RATIO: f64 = -2.0;
+/// Ratio limit sentinel for "no limit".
+const NO_RATIO_LIMIT: f64 = -1.0;
+/// Seeding time limit sentinel for "use the global limit".
+const USE_GLOBAL_SEEDING_TIME: i64 = -2;
+/// Seeding time limit sentinel for "no limit".
+const NO_SEEDING_TIME_LIMIT: i64 = -1;
+
+impl QBittorrentClient {
+    /// Get the download speed limit (bytes/s) of torrents keyed by hash
+    ///
+    /// A value of `0` or less means no limit.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-torrent-download-limit>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-torrent-download-limit>
+    pub async fn get_download_limits(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<HashMap<String, i64>>, Failure<ClientAction>> {
+        self.get_limits("/torrents/downloadLimit", &hashes).await
+    }
+
+    /// Set the download speed limit (bytes/s) of torrents
+    ///
+    /// A `limit` of `0` removes the limit.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#set-torrent-download-limit>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#set-torrent-download-limit>
+    pub async fn set_download_limit(
+        &self,
+        hashes: Vec<String>,
+        limit: u64,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        self.set_limit("/torrents/setDownloadLimit", &hashes, limit)
+            .await
+    }
+
+    /// Get the upload speed limit (bytes/s) of torrents keyed by hash
+    ///
+    /// A value of `0` or less means no limit.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-torrent-upload-limit>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-torrent-upload-limit>
+    pub async fn get_upload_limits(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<HashMap<String, i64>>, Failure<ClientAction>> {
+        self.get_limits("/torrents/uploadLimit", &hashes).await
+    }
+
+    /// Set the upload speed limit (bytes/s) of torrents
+    ///
+    /// A `limit` of `0` removes the limit.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#set-torrent-upload-limit>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#set-torrent-upload-limit>
+    pub async fn set_upload_limit(
+        &self,
+        hashes: Vec<String>,
+        limit: u64,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        self.set_limit("/torrents/setUploadLimit", &hashes, limit)
+            .await
+    }
+
+    /// Set the share ratio and seeding time limits of torrents
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#set-torrent-share-limit>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#set-torrent-share-limit>
+    pub async fn set_share_limits(
+        &self,
+        hashes: Vec<String>,
+        limits: ShareLimits,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        let data = vec![
+            ("hashes", join_hashes(&hashes)),
+            ("ratioLimit", limits.ratio_limit.to_ratio().to_string()),
+            (
+                "seedingTimeLimit",
+                limits.seeding_time_limit.to_minutes().to_string(),
+            ),
+            (
+                "inactiveSeedingTimeLimit",
+                limits.inactive_seeding_time_limit.to_minutes().to_string(),
+            ),
+        ];
+        let response = self
+            .request_with_login(Method::POST, "/torrents/setShareLimits", &data)
+            .await?;
+        Ok(success_response(&response))
+    }
+
+    async fn get_limits(
+        &self,
+        endpoint: &str,
+        hashes: &[String],
+    ) -> Result<Response<HashMap<String, i64>>, Failure<ClientAction>> {
+        let method = Method::POST;
+        let data = vec![("hashes", join_hashes(hashes))];
+        let response = self
+            .request_with_login(method.clone(), endpoint, &data)
+            .await?;
+        deserialize_response::<HashMap<String, i64>>(&method, endpoint, response).await
+    }
+
+    async fn set_limit(
+        &self,
+        endpoint: &str,
+        hashes: &[String],
+        limit: u64,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        let data = vec![
+            ("hashes", join_hashes(hashes)),
+            ("limit", limit.to_string()),
+        ];
+        let response = self
+            .request_with_login(Method::POST, endpoint, &data)
+            .await?;
+        Ok(success_response(&response))
+    }
+}
+
+/// A per-torrent share limit.
+///
+/// Replaces the `-2` (use global limit) and `-1` (no limit) sentinels used by the API.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/base/bittorrent/torrent.cpp>
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub enum ShareLimit<T> {
+    /// Use the global share limit.
+    #[default]
+    Global,
+    /// No share limit.
+    Unlimited,
+    /// Specific share limit.
+    Value(T),
+}
+
+impl ShareLimit<f64> {
+    /// Create from a ratio limit as returned by the API.
+    #[must_use]
+    pub fn from_ratio(value: f64) -> Self {
+        if value <= USE_GLOBAL_RATIO {
+            ShareLimit::Global
+        } else if value <= NO_RATIO_LIMIT {
+            ShareLimit::Unlimited
+        } else {
+            ShareLimit::Value(value)
+        }
+    }
+
+    /// Convert to a ratio limit as expected by the API.
+    #[must_use]
+    pub fn to_ratio(self) -> f64 {
+        match self {
+            ShareLimit::Global => USE_GLOBAL_RATIO,
+            ShareLimit::Unlimited => NO_RATIO_LIMIT,
+            ShareLimit::Value(value) => value,
+        }
+    }
+}
+
+impl ShareLimit<i64> {
+    /// Create from a seeding time limit as returned by the API.
+    #[must_use]
+    pub fn from_minutes(value: i64) -> Self {
+        match value {
+            ..=USE_GLOBAL_SEEDING_TIME => ShareLimit::Global,
+            NO_SEEDING_TIME_LIMIT => ShareLimit::Unlimited,
+            _ => ShareLimit::Value(value),
+        }
+    }
+
+    /// Convert to a seeding time limit in minutes as expected by the API.
+    #[must_use]
+    pub fn to_minutes(self) -> i64 {
+        match self {
+            ShareLimit::Global => USE_GLOBAL_SEEDING_TIME,
+            ShareLimit::Unlimited => NO_SEEDING_TIME_LIMIT,
+            ShareLimit::Value(value) => value,
+        }
+    }
+}
+
+/// Share limits to apply with
+/// [`QBittorrentClient::set_share_limits`](crate::QBittorrentClient::set_share_limits).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct ShareLimits {
+    /// Share ratio limit.
+    pub ratio_limit: ShareLimit<f64>,
+
+    /// Seeding time limit in minutes.
+    pub seeding_time_limit: ShareLimit<i64>,
+
+    /// Inactive seeding time limit in minutes.
+    ///
+    /// Added in v4.6. Ignored by older servers.
+    pub inactive_seeding_time_limit: ShareLimit<i64>,
+}
+
+impl Torrent {
+    /// Typed per-torrent share ratio limit.
+    #[must_use]
+    pub fn ratio_share_limit(&self) -> ShareLimit<f64> {
+        ShareLimit::from_ratio(self.ratio_limit)
+    }
+
+    /// Typed per-torrent seeding time limit.
+    #[must_use]
+    pub fn seeding_time_share_limit(&self) -> ShareLimit<i64> {
+        ShareLimit::from_minutes(self.seeding_time_limit)
+    }
+
+    /// Typed per-torrent inactive seeding time limit.
+    ///
+    /// Added in v4.6
+    #[must_use]
+    pub fn inactive_seeding_time_share_limit(&self) -> Option<ShareLimit<i64>> {
+        self.inactive_seeding_time_limit
+            .map(ShareLimit::from_minutes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ratio_from_sentinels() {
+        assert_eq!(ShareLimit::from_ratio(-2.0), ShareLimit::Global);
+        assert_eq!(ShareLimit::from_ratio(-1.0), ShareLimit::Unlimited);
+        assert_eq!(ShareLimit::from_ratio(1.5), ShareLimit::Value(1.5));
+        assert_eq!(ShareLimit::from_ratio(0.0), ShareLimit::Value(0.0));
+    }
+
+    #[test]
+    fn ratio_round_trip() {
+        for limit in [
+            ShareLimit::Global,
+            ShareLimit::Unlimited,
+            ShareLimit::Value(2.5),
+        ] {
+            assert_eq!(ShareLimit::from_ratio(limit.to_ratio()), limit);
+        }
+    }
+
+    #[test]
+    fn minutes_from_sentinels() {
+        assert_eq!(ShareLimit::from_minutes(-2), ShareLimit::Global);
+        assert_eq!(ShareLimit::from_minutes(-1), ShareLimit::Unlimited);
+        assert_eq!(ShareLimit::from_minutes(1440), ShareLimit::Value(1440));
+    }
+
+    #[test]
+    fn minutes_round_trip() {
+        for limit in [
+            ShareLimit::Global,
+            ShareLimit::Unlimited,
+            ShareLimit::Value(60),
+        ] {
+            assert_eq!(ShareLimit::from_minutes(limit.to_minutes()), limit);
+        }
+    }
+
+    #[test]
+    fn deserialize_limits() {
+        let json = r#"{"a1b2c3d4e5f6a1b2c3d4e5f6a1b2c3d4e5f6a1b2": 1048576, "b2c3": -1}"#;
+        let limits: HashMap<String, i64> =
+            serde_json::from_str(json).expect("limits should deserialize");
+        assert_eq!(
+            limits.get("a1b2c3d4e5f6a1b2c3d4e5f6a1b2c3d4e5f6a1b2"),
+            Some(&1_048_576)
+        );
+        assert_eq!(limits.get("b2c3"), Some(&-1));
+    }
+}
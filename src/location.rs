@@ -0,0 +1,286 @@
+//! Torrent storage relocation and waiting for moves to complete.
+
+use std::collections::HashSet;
+
+use crate::app::Version;
+use crate::client::{join_hashes, success_response};
+use crate::get_torrents::{State, Torrent};
+use crate::wait::{PollOutcome, WaitOptions};
+use crate::{QBittorrentClient, Response};
+use colored::Colorize;
+use log::debug;
+use reqwest::Method;
+use rogue_logging::Failure;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// First qBittorrent version with `/torrents/setSavePath` and `/torrents/setDownloadPath`.
+const SET_PATH_VERSION: Version = Version::new(4, 5, 0);
+
+impl QBittorrentClient {
+    /// Move torrents to a new location
+    ///
+    /// Automatic Torrent Management is disabled for the torrents.
+    ///
+    /// Returns [`LocationAction::PermissionDenied`] if `path` is not writable.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#set-torrent-location>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#set-torrent-location>
+    pub async fn set_location(
+        &self,
+        hashes: Vec<String>,
+        path: String,
+    ) -> Result<Response<bool>, Failure<LocationAction>> {
+        let data = vec![("hashes", join_hashes(&hashes)), ("location", path.clone())];
+        self.send_location("/torrents/setLocation", &data, path)
+            .await
+    }
+
+    /// Set the save path of torrents
+    ///
+    /// Added in v4.5. Returns [`LocationAction::Request`] wrapping
+    /// [`ClientAction::CheckSupport`](crate::ClientAction::CheckSupport) on older servers.
+    pub async fn set_save_path(
+        &self,
+        hashes: Vec<String>,
+        path: String,
+    ) -> Result<Response<bool>, Failure<LocationAction>> {
+        let endpoint = "/torrents/setSavePath";
+        self.require_version(SET_PATH_VERSION, endpoint)
+            .await
+            .map_err(Failure::wrap(LocationAction::Request))?;
+        let data = vec![("id", join_hashes(&hashes)), ("path", path.clone())];
+        self.send_location(endpoint, &data, path).await
+    }
+
+    /// Set the download path of incomplete torrents
+    ///
+    /// Added in v4.5. Returns [`LocationAction::Request`] wrapping
+    /// [`ClientAction::CheckSupport`](crate::ClientAction::CheckSupport) on older servers.
+    pub async fn set_download_path(
+        &self,
+        hashes: Vec<String>,
+        path: String,
+    ) -> Result<Response<bool>, Failure<LocationAction>> {
+        let endpoint = "/torrents/setDownloadPath";
+        self.require_version(SET_PATH_VERSION, endpoint)
+            .await
+            .map_err(Failure::wrap(LocationAction::Request))?;
+        let data = vec![("id", join_hashes(&hashes)), ("path", path.clone())];
+        self.send_location(endpoint, &data, path).await
+    }
+
+    /// Move torrents to a new location and wait for each move to complete
+    ///
+    /// - Torrents are moved in batches of [`WaitOptions::batch_size`] so that only a limited
+    ///   number of moves run concurrently on the server.
+    /// - A torrent has moved once it is no longer [`State::Moving`] and its `save_path`
+    ///   matches `path`.
+    /// - A torrent has failed to move if it enters an error state, or stops being
+    ///   [`State::Moving`] while its `save_path` does not match `path`.
+    /// - A torrent that never starts moving is reported as [`MoveStatus::TimedOut`] once
+    ///   [`WaitOptions::timeout`] elapses.
+    /// - Results are returned in the order of `hashes`.
+    pub async fn move_and_wait(
+        &self,
+        hashes: Vec<String>,
+        path: String,
+        options: WaitOptions,
+    ) -> Result<Vec<MoveResult>, Failure<LocationAction>> {
+        let mut results = Vec::with_capacity(hashes.len());
+        for batch in hashes.chunks(options.batch_size(hashes.len())) {
+            debug!("{} {} torrents to {path}", "Moving".bold(), batch.len());
+            self.set_location(batch.to_vec(), path.clone())
+                .await?
+                .get_result("set_location")
+                .map_err(Failure::wrap(LocationAction::Request))?;
+            let mut tracker = MoveTracker::default();
+            let outcomes = self
+                .poll_torrents(batch, &options, |torrent| {
+                    tracker.get_status(torrent, &path)
+                })
+                .await
+                .map_err(Failure::wrap(LocationAction::Request))?;
+            results.extend(outcomes.into_iter().map(|(hash, outcome)| {
+                let status = match outcome {
+                    PollOutcome::Complete(status) => status,
+                    PollOutcome::NotFound => MoveStatus::NotFound,
+                    PollOutcome::TimedOut(_) => MoveStatus::TimedOut,
+                };
+                MoveResult { hash, status }
+            }));
+        }
+        Ok(results)
+    }
+
+    async fn send_location(
+        &self,
+        endpoint: &str,
+        data: &[(&str, String)],
+        path: String,
+    ) -> Result<Response<bool>, Failure<LocationAction>> {
+        let response = self
+            .request_with_login(Method::POST, endpoint, &data)
+            .await
+            .map_err(Failure::wrap(LocationAction::Request))?;
+        let action = match response.status().as_u16() {
+            400 => LocationAction::InvalidPath,
+            403 => LocationAction::PermissionDenied,
+            409 => LocationAction::CreateDirectory,
+            _ => return Ok(success_response(&response)),
+        };
+        Err(Failure::from_action(action)
+            .with("endpoint", endpoint)
+            .with("path", path)
+            .with("status_code", response.status().as_u16().to_string()))
+    }
+}
+
+/// Track which moves have started so a move that ends without changing the save path is
+/// reported as failed.
+#[derive(Default)]
+struct MoveTracker {
+    /// Hashes of torrents seen in [`State::Moving`].
+    started: HashSet<String>,
+}
+
+impl MoveTracker {
+    /// Determine whether a move has finished, returning `None` while it is still in
+    /// progress or has not yet started.
+    fn get_status(&mut self, torrent: &Torrent, path: &str) -> Option<MoveStatus> {
+        match torrent.state {
+            State::Moving => {
+                self.started.insert(torrent.hash.clone());
+                None
+            }
+            State::Error | State::MissingFiles => Some(MoveStatus::Failed(torrent.state.clone())),
+            _ if normalize_path(&torrent.save_path) == normalize_path(path) => {
+                Some(MoveStatus::Moved)
+            }
+            _ if self.started.contains(&torrent.hash) => {
+                Some(MoveStatus::Failed(torrent.state.clone()))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Remove trailing path separators so `/downloads/` and `/downloads` compare equal.
+fn normalize_path(path: &str) -> &str {
+    path.trim_end_matches(['/', '\\'])
+}
+
+/// Outcome of moving a single torrent with
+/// [`QBittorrentClient::move_and_wait`](crate::QBittorrentClient::move_and_wait).
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MoveResult {
+    /// Torrent hash.
+    pub hash: String,
+    /// Outcome of the move.
+    pub status: MoveStatus,
+}
+
+/// Outcome of a torrent move.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum MoveStatus {
+    /// Torrent finished moving and its save path matches the requested path.
+    Moved,
+    /// Torrent entered an error state, or stopped moving without its save path changing.
+    ///
+    /// Contains the state of the torrent when the failure was detected.
+    Failed(State),
+    /// Torrent was not found on the server.
+    NotFound,
+    /// Torrent did not finish moving before [`WaitOptions::timeout`].
+    TimedOut,
+}
+
+/// Errors returned by torrent location operations
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Error)]
+pub enum LocationAction {
+    #[error("send request")]
+    Request,
+    #[error("validate path")]
+    InvalidPath,
+    /// The server answered 403 after logging in again.
+    ///
+    /// The server answers 403 if the path is not writable, which can't be told apart from an
+    /// expired session. So the stored session is cleared and the request is retried after
+    /// logging in before this is returned.
+    #[error("write to path")]
+    PermissionDenied,
+    #[error("create directory")]
+    CreateDirectory,
+}
+
+#[cfg(test)]
+#[expect(clippy::indexing_slicing, reason = "indexing fixture torrents")]
+mod tests {
+    use super::*;
+
+    const FIXTURE_V5: &str = include_str!("tests/fixtures/torrents_info_v5.json");
+
+    fn fixture_torrent() -> Torrent {
+        let torrents: Vec<Torrent> =
+            serde_json::from_str(FIXTURE_V5).expect("v5 fixture should deserialize");
+        torrents[0].clone()
+    }
+
+    #[test]
+    fn move_status_moving() {
+        let mut torrent = fixture_torrent();
+        torrent.state = State::Moving;
+        torrent.save_path = "/archive".to_owned();
+        assert_eq!(
+            MoveTracker::default().get_status(&torrent, "/archive"),
+            None
+        );
+    }
+
+    #[test]
+    fn move_status_moved() {
+        let mut torrent = fixture_torrent();
+        torrent.state = State::StalledUP;
+        torrent.save_path = "/archive/".to_owned();
+        assert_eq!(
+            MoveTracker::default().get_status(&torrent, "/archive"),
+            Some(MoveStatus::Moved)
+        );
+    }
+
+    #[test]
+    fn move_status_not_yet_started() {
+        let mut torrent = fixture_torrent();
+        torrent.state = State::StalledUP;
+        torrent.save_path = "/downloads".to_owned();
+        assert_eq!(
+            MoveTracker::default().get_status(&torrent, "/archive"),
+            None
+        );
+    }
+
+    #[test]
+    fn move_status_failed() {
+        let mut torrent = fixture_torrent();
+        torrent.state = State::Error;
+        assert_eq!(
+            MoveTracker::default().get_status(&torrent, "/archive"),
+            Some(MoveStatus::Failed(State::Error))
+        );
+    }
+
+    #[test]
+    fn move_status_stopped_moving_at_old_path() {
+        let mut torrent = fixture_torrent();
+        torrent.save_path = "/downloads".to_owned();
+        let mut tracker = MoveTracker::default();
+        torrent.state = State::Moving;
+        assert_eq!(tracker.get_status(&torrent, "/archive"), None);
+        torrent.state = State::StalledUP;
+        assert_eq!(
+            tracker.get_status(&torrent, "/archive"),
+            Some(MoveStatus::Failed(State::StalledUP))
+        );
+    }
+}
@@ -1,18 +1,29 @@
 //! Session authentication and cookie management.
 
-use crate::client::{ClientAction, handle_status_response};
-use crate::{QBittorrentClient, Status};
+use std::ops::Deref;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+
+use crate::client::{ClientAction, handle_status_response, success_response};
+use crate::{QBittorrentClient, Response, Status};
+use colored::Colorize;
+use log::{debug, warn};
 use reqwest::Method;
-use reqwest::cookie::CookieStore;
+use reqwest::cookie::{CookieStore, Jar};
 use rogue_logging::Failure;
+use tokio::runtime::Handle;
 
 impl QBittorrentClient {
     /// Login and get a session cookie
     ///
+    /// Requests log in automatically, call this to check the credentials up front.
+    /// Use [`QBittorrentClient::ensure_login`] to fail on invalid credentials instead of
+    /// inspecting the [`Status`].
+    ///
     /// # See Also
     /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#login>
     /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#login>
-    pub(crate) async fn login(&self) -> Result<Status, Failure<ClientAction>> {
+    pub async fn login(&self) -> Result<Status, Failure<ClientAction>> {
         let method = Method::POST;
         let endpoint = "/auth/login";
         let username = self.username.clone();
@@ -22,7 +33,11 @@ impl QBittorrentClient {
             ("password", password.as_str()),
         ];
         let response = self.request(method.clone(), endpoint, &data).await?;
-        handle_status_response(&method, endpoint, response).await
+        let status = handle_status_response(&method, endpoint, response).await?;
+        if status == Status::Success {
+            self.save_session();
+        }
+        Ok(status)
     }
 
     /// Check if the cookie jar contains a session cookie for the host
@@ -31,27 +46,141 @@ impl QBittorrentClient {
         self.cookies.cookies(&url).is_some()
     }
 
-    /// Login if no session cookie exists
-    pub(crate) async fn ensure_login(&self) -> Result<(), Failure<ClientAction>> {
-        if !self.has_session_cookie() {
-            let status = self.login().await?;
-            if status != Status::Success {
-                return Err(
-                    Failure::from_action(ClientAction::Login).with("status", format!("{status:?}"))
-                );
-            }
+    /// Login if no valid session cookie exists
+    ///
+    /// A session cookie loaded from the session store is checked with a request to
+    /// `/app/version` the first time this is called. If it is rejected it is removed from the
+    /// session store and a new session is started.
+    ///
+    /// Returns [`ClientAction::Login`] if the credentials are rejected.
+    pub async fn ensure_login(&self) -> Result<(), Failure<ClientAction>> {
+        if self.has_session_cookie() && self.verify_stored_session().await? {
+            return Ok(());
+        }
+        let status = self.login().await?;
+        if status != Status::Success {
+            return Err(
+                Failure::from_action(ClientAction::Login).with("status", format!("{status:?}"))
+            );
         }
         Ok(())
     }
+
+    /// Check the session cookie is accepted if it was loaded from the session store.
+    ///
+    /// Returns `false` and clears the session if it was rejected.
+    async fn verify_stored_session(&self) -> Result<bool, Failure<ClientAction>> {
+        if !self.unverified_session.swap(false, Ordering::SeqCst) {
+            return Ok(true);
+        }
+        let response = self.request(Method::GET, "/app/version", &()).await?;
+        if response.status().as_u16() != 403 {
+            return Ok(true);
+        }
+        debug!("{} stored session cookie", "Rejected".bold());
+        let url = reqwest::Url::parse(&self.host).expect("host should be a valid URL");
+        clear_cookies(&self.cookies, &url);
+        self.clear_session();
+        Ok(false)
+    }
+
+    /// Logout and clear the session cookie, including from the session store
+    ///
+    /// The next request logs in again.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#logout>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#logout>
+    pub async fn logout(&self) -> Result<Response<bool>, Failure<ClientAction>> {
+        let response = self.request(Method::POST, "/auth/logout", &()).await?;
+        let url = reqwest::Url::parse(&self.host).expect("host should be a valid URL");
+        clear_cookies(&self.cookies, &url);
+        self.clear_session();
+        Ok(success_response(&response))
+    }
+}
+
+/// Expire every cookie stored for `url`.
+///
+/// The session cookie name varies between qBittorrent versions so all cookies are expired.
+fn clear_cookies(cookies: &Jar, url: &reqwest::Url) {
+    let Some(header) = cookies.cookies(url) else {
+        return;
+    };
+    let Ok(header) = header.to_str() else {
+        return;
+    };
+    for cookie in header.split("; ") {
+        if let Some((name, _)) = cookie.split_once('=') {
+            cookies.add_cookie_str(&format!("{name}=; Max-Age=0; Path=/"), url);
+        }
+    }
+}
+
+/// A logged in session that logs out when it goes away.
+///
+/// Dereferences to the [`QBittorrentClient`] so requests can be made through the session.
+///
+/// Prefer [`Session::close`] which waits for the logout to complete. On drop the logout is
+/// spawned on the current Tokio runtime, so it may not complete if the runtime is shutting down.
+pub struct Session {
+    client: Option<Arc<QBittorrentClient>>,
+}
+
+impl Session {
+    /// Login and start a session
+    ///
+    /// Returns [`ClientAction::Login`] if the credentials are rejected.
+    pub async fn start(client: Arc<QBittorrentClient>) -> Result<Self, Failure<ClientAction>> {
+        client.ensure_login().await?;
+        Ok(Self {
+            client: Some(client),
+        })
+    }
+
+    /// Logout and end the session
+    pub async fn close(mut self) -> Result<Response<bool>, Failure<ClientAction>> {
+        let client = self.client.take().expect("session should be open");
+        client.logout().await
+    }
+}
+
+impl Deref for Session {
+    type Target = QBittorrentClient;
+
+    fn deref(&self) -> &QBittorrentClient {
+        self.client.as_ref().expect("session should be open")
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        let Some(client) = self.client.take() else {
+            return;
+        };
+        let Ok(handle) = Handle::try_current() else {
+            warn!("Unable to logout as no Tokio runtime is available");
+            return;
+        };
+        handle.spawn(async move {
+            if let Err(failure) = client.logout().await {
+                warn!("Unable to logout: {failure}");
+            }
+        });
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::QBittorrentClientOptions;
+    use super::*;
+    use crate::session_store::{FileSessionStore, SessionStore};
     use crate::tests::init_logger;
-    use crate::{QBittorrentClient, Status};
+    use crate::{QBittorrentClientFactory, QBittorrentClientOptions};
     use rogue_config::{OptionsProvider, YamlOptionsProvider};
+    use std::env::temp_dir;
     use std::error::Error;
+    use std::fs::remove_file;
+    use std::process;
 
     #[tokio::test]
     #[ignore = "integration test requiring API credentials"]
@@ -70,4 +199,85 @@ mod tests {
         assert!(client.has_session_cookie());
         Ok(())
     }
+
+    #[tokio::test]
+    #[ignore = "integration test requiring API credentials"]
+    async fn logout() -> Result<(), Box<dyn Error>> {
+        // Arrange
+        init_logger();
+        let options: QBittorrentClientOptions =
+            YamlOptionsProvider::get().map_err(|e| e.to_string())?;
+        let client = QBittorrentClient::from_options(options);
+        client.ensure_login().await?;
+
+        // Act
+        let response = client.logout().await?;
+
+        // Assert
+        assert!(response.get_result("logout")?);
+        assert!(!client.has_session_cookie());
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "integration test requiring API credentials"]
+    async fn session_close() -> Result<(), Box<dyn Error>> {
+        // Arrange
+        init_logger();
+        let options: QBittorrentClientOptions =
+            YamlOptionsProvider::get().map_err(|e| e.to_string())?;
+        let client = Arc::new(QBittorrentClient::from_options(options));
+        let session = Session::start(client.clone()).await?;
+        let _version = session.get_version().await?.get_result("get_version")?;
+
+        // Act
+        let response = session.close().await?;
+
+        // Assert
+        assert!(response.get_result("logout")?);
+        assert!(!client.has_session_cookie());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn ensure_login_checks_stored_session() {
+        // Arrange
+        let host = "http://127.0.0.1:9";
+        let path = temp_dir().join(format!(
+            "qbittorrent_api_ensure_login_{}.json",
+            process::id()
+        ));
+        let store = FileSessionStore::new(&path);
+        store
+            .save(host, "SID=abc123")
+            .expect("session should be saved");
+        let factory = QBittorrentClientFactory {
+            options: QBittorrentClientOptions {
+                host: host.to_owned(),
+                ..QBittorrentClientOptions::default()
+            },
+        };
+        let client = factory.create_with_session_store(Arc::new(store));
+
+        // Act
+        let error = client
+            .ensure_login()
+            .await
+            .expect_err("stored session should be checked");
+
+        // Assert
+        assert!(client.has_session_cookie());
+        assert_eq!(error.action(), &ClientAction::SendRequest);
+        let _ = remove_file(&path);
+    }
+
+    #[test]
+    fn clear_cookies_expires_session_cookie() {
+        let url = reqwest::Url::parse("http://127.0.0.1:8080").expect("url should be valid");
+        let cookies = Jar::default();
+        cookies.add_cookie_str("SID=abc123; HttpOnly; Path=/", &url);
+        cookies.add_cookie_str("QBT_SID_8080=def456; HttpOnly; Path=/", &url);
+        clear_cookies(&cookies, &url);
+        assert!(cookies.cookies(&url).is_none());
+    }
 }
@@ -0,0 +1,354 @@
+//! Main and peer logs, incremental log tailing and forwarding to the `log` crate.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::client::{ClientAction, deserialize_response};
+use crate::{QBittorrentClient, Response};
+use bitflags::bitflags;
+use futures_util::{Stream, StreamExt, stream::unfold};
+use log::{Level, log};
+use reqwest::Method;
+use rogue_logging::Failure;
+use serde::{Deserialize, Serialize};
+use tokio::time::sleep;
+
+/// Target used when forwarding log entries to the `log` crate.
+const LOG_TARGET: &str = "qbittorrent";
+
+impl QBittorrentClient {
+    /// Get main log entries matching `filter`
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-log>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-log>
+    pub async fn get_main_log(
+        &self,
+        filter: LogFilter,
+    ) -> Result<Response<Vec<LogEntry>>, Failure<ClientAction>> {
+        let method = Method::GET;
+        let endpoint = "/log/main";
+        let response = self
+            .request_with_login(method.clone(), endpoint, &filter.to_query())
+            .await?;
+        deserialize_response::<Vec<LogEntry>>(&method, endpoint, response).await
+    }
+
+    /// Get peer log entries newer than `last_known_id`
+    ///
+    /// `None` gets all entries.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-peer-log>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-peer-log>
+    pub async fn get_peer_log(
+        &self,
+        last_known_id: Option<i64>,
+    ) -> Result<Response<Vec<PeerLogEntry>>, Failure<ClientAction>> {
+        let method = Method::GET;
+        let endpoint = "/log/peers";
+        let query = vec![("last_known_id", last_known_id.unwrap_or(-1).to_string())];
+        let response = self
+            .request_with_login(method.clone(), endpoint, &query)
+            .await?;
+        deserialize_response::<Vec<PeerLogEntry>>(&method, endpoint, response).await
+    }
+
+    /// Stream main log entries matching `filter` as they are logged
+    ///
+    /// - Entries newer than `filter.last_known_id` are returned first, then the log is polled
+    ///   every `poll_interval` for new entries.
+    /// - A failed poll is returned as an error and polling continues after `poll_interval`.
+    /// - The stream never ends.
+    pub fn tail_main_log(
+        &self,
+        filter: LogFilter,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<LogEntry, Failure<ClientAction>>> + '_ {
+        let tail = LogTail {
+            filter,
+            pending: VecDeque::new(),
+            polled: false,
+        };
+        unfold(tail, move |mut tail| async move {
+            loop {
+                if let Some(entry) = tail.pending.pop_front() {
+                    return Some((Ok(entry), tail));
+                }
+                if tail.polled {
+                    sleep(poll_interval).await;
+                }
+                tail.polled = true;
+                let entries = self
+                    .get_main_log(tail.filter.clone())
+                    .await
+                    .and_then(|response| response.get_result("get_main_log"));
+                match entries {
+                    Ok(entries) => {
+                        if let Some(last) = entries.last() {
+                            tail.filter.last_known_id = Some(last.id);
+                        }
+                        tail.pending.extend(entries);
+                    }
+                    Err(failure) => return Some((Err(failure), tail)),
+                }
+            }
+        })
+    }
+
+    /// Forward main log entries matching `filter` to the `log` crate as they are logged
+    ///
+    /// Entries are logged with the `qbittorrent` target at the level of
+    /// [`LogEntry::get_level`].
+    ///
+    /// Runs until a poll fails.
+    pub async fn forward_main_log(
+        &self,
+        filter: LogFilter,
+        poll_interval: Duration,
+    ) -> Result<(), Failure<ClientAction>> {
+        let mut entries = Box::pin(self.tail_main_log(filter, poll_interval));
+        while let Some(entry) = entries.next().await {
+            entry?.forward();
+        }
+        Ok(())
+    }
+}
+
+/// State of [`QBittorrentClient::tail_main_log`] between polls.
+struct LogTail {
+    filter: LogFilter,
+    pending: VecDeque<LogEntry>,
+    polled: bool,
+}
+
+bitflags! {
+    /// Severity of a main log entry.
+    ///
+    /// Entries have a single level. Combine levels to filter with [`LogFilter`].
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+    #[serde(try_from = "u8", into = "u8")]
+    pub struct LogLevel: u8 {
+        /// Normal message.
+        const NORMAL = 1;
+        /// Informational message.
+        const INFO = 2;
+        /// Warning message.
+        const WARNING = 4;
+        /// Critical message.
+        const CRITICAL = 8;
+    }
+}
+
+impl TryFrom<u8> for LogLevel {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        LogLevel::from_bits(value).ok_or_else(|| format!("unknown log level: {value}"))
+    }
+}
+
+impl From<LogLevel> for u8 {
+    fn from(level: LogLevel) -> Self {
+        level.bits()
+    }
+}
+
+/// Filter options for [`QBittorrentClient::get_main_log`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogFilter {
+    /// Levels to include.
+    pub levels: LogLevel,
+
+    /// Only include entries with an id greater than this.
+    ///
+    /// `None` includes all entries.
+    pub last_known_id: Option<i64>,
+}
+
+impl LogFilter {
+    /// Build the query parameters for `/log/main`.
+    fn to_query(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("normal", self.levels.contains(LogLevel::NORMAL).to_string()),
+            ("info", self.levels.contains(LogLevel::INFO).to_string()),
+            (
+                "warning",
+                self.levels.contains(LogLevel::WARNING).to_string(),
+            ),
+            (
+                "critical",
+                self.levels.contains(LogLevel::CRITICAL).to_string(),
+            ),
+            (
+                "last_known_id",
+                self.last_known_id.unwrap_or(-1).to_string(),
+            ),
+        ]
+    }
+}
+
+impl Default for LogFilter {
+    fn default() -> Self {
+        Self {
+            levels: LogLevel::all(),
+            last_known_id: None,
+        }
+    }
+}
+
+/// A main log entry.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-log>
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/webui/api/logcontroller.cpp>
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LogEntry {
+    /// Id of the entry, increasing with each entry.
+    pub id: i64,
+
+    /// Message text.
+    pub message: String,
+
+    /// Time the entry was logged (Unix time).
+    pub timestamp: i64,
+
+    /// Severity.
+    #[serde(rename = "type")]
+    pub level: LogLevel,
+}
+
+impl LogEntry {
+    /// Level to log the entry at with the `log` crate.
+    ///
+    /// Informational entries are more verbose than normal entries so are logged at `Debug`.
+    #[must_use]
+    pub fn get_level(&self) -> Level {
+        if self.level.contains(LogLevel::CRITICAL) {
+            Level::Error
+        } else if self.level.contains(LogLevel::WARNING) {
+            Level::Warn
+        } else if self.level.contains(LogLevel::NORMAL) {
+            Level::Info
+        } else {
+            Level::Debug
+        }
+    }
+
+    /// Log the entry with the `log` crate using the `qbittorrent` target.
+    pub fn forward(&self) {
+        log!(target: LOG_TARGET, self.get_level(), "{}", self.message);
+    }
+}
+
+/// A peer log entry.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-peer-log>
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/webui/api/logcontroller.cpp>
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PeerLogEntry {
+    /// Id of the entry, increasing with each entry.
+    pub id: i64,
+
+    /// Peer IP address.
+    pub ip: String,
+
+    /// Time the entry was logged (Unix time).
+    pub timestamp: i64,
+
+    /// Whether the peer was blocked.
+    pub blocked: bool,
+
+    /// Reason the peer was blocked.
+    pub reason: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QBittorrentClientOptions;
+    use crate::tests::init_logger;
+    use insta::assert_yaml_snapshot;
+    use log::trace;
+    use rogue_config::{OptionsProvider, YamlOptionsProvider};
+    use std::error::Error;
+
+    const MAIN_LOG_V5: &str = include_str!("tests/fixtures/log_main_v5.json");
+    const PEER_LOG_V5: &str = include_str!("tests/fixtures/log_peers_v5.json");
+
+    #[tokio::test]
+    #[ignore = "integration test requiring API credentials"]
+    async fn get_main_log() -> Result<(), Box<dyn Error>> {
+        // Arrange
+        init_logger();
+        let options: QBittorrentClientOptions =
+            YamlOptionsProvider::get().map_err(|e| e.to_string())?;
+        let client = QBittorrentClient::from_options(options);
+        let filter = LogFilter {
+            levels: LogLevel::WARNING | LogLevel::CRITICAL,
+            ..LogFilter::default()
+        };
+
+        // Act
+        let response = client.get_main_log(filter).await?;
+        trace!("{}", response.to_json_pretty());
+
+        // Assert
+        let result = response.get_result("get_main_log")?;
+        assert!(result.iter().all(|entry| {
+            entry
+                .level
+                .intersects(LogLevel::WARNING | LogLevel::CRITICAL)
+        }));
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_main_log() {
+        let entries: Vec<LogEntry> =
+            serde_json::from_str(MAIN_LOG_V5).expect("main log should deserialize");
+        assert_yaml_snapshot!(entries);
+    }
+
+    #[test]
+    fn deserialize_peer_log() {
+        let entries: Vec<PeerLogEntry> =
+            serde_json::from_str(PEER_LOG_V5).expect("peer log should deserialize");
+        assert_yaml_snapshot!(entries);
+    }
+
+    #[test]
+    fn deserialize_unknown_log_level() {
+        assert!(serde_json::from_str::<LogLevel>("16").is_err());
+    }
+
+    #[test]
+    fn log_entry_level() {
+        let entries: Vec<LogEntry> =
+            serde_json::from_str(MAIN_LOG_V5).expect("main log should deserialize");
+        let levels: Vec<Level> = entries.iter().map(LogEntry::get_level).collect();
+        assert_eq!(
+            levels,
+            vec![Level::Info, Level::Debug, Level::Warn, Level::Error]
+        );
+    }
+
+    #[test]
+    fn log_filter_query() {
+        let filter = LogFilter {
+            levels: LogLevel::WARNING | LogLevel::CRITICAL,
+            last_known_id: Some(41),
+        };
+        assert_eq!(
+            filter.to_query(),
+            vec![
+                ("normal", "false".to_owned()),
+                ("info", "false".to_owned()),
+                ("warning", "true".to_owned()),
+                ("critical", "true".to_owned()),
+                ("last_known_id", "41".to_owned()),
+            ]
+        );
+    }
+}
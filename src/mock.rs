@@ -1,12 +1,32 @@
 //! Mock implementation of [`QBittorrentClientTrait`] for testing.
 
 use async_trait::async_trait;
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use crate::add_torrent::{AddTorrentAction, AddTorrentOptions};
+use crate::app::BuildInfo;
+use crate::categories::{Category, CategoryAction};
 use crate::client::ClientAction;
+use crate::directory::{DirectoryAction, DirectoryContentMode, DirectoryEntry};
 use crate::get_torrents::{FilterOptions, Torrent};
-use crate::{QBittorrentClientTrait, Response};
+use crate::limits::ShareLimits;
+use crate::location::LocationAction;
+use crate::logs::{LogEntry, LogFilter, PeerLogEntry};
+use crate::peers::PeerAddr;
+use crate::peers::{PeerAction, PeersUpdate};
+use crate::pieces::{PieceAction, PieceState};
+use crate::preferences::{Preferences, PreferencesPatch};
+use crate::queue::QueueAction;
+use crate::rename::RenameAction;
+use crate::rss::{RssAction, RssFolder};
+use crate::rss_rules::AutoDownloadRule;
+use crate::search::{SearchAction, SearchQuery, SearchResults, SearchStatus};
+use crate::search_plugins::SearchPlugin;
+use crate::torrent_creator::{TorrentCreationStatus, TorrentCreationTask, TorrentCreatorAction};
+use crate::transfer::{SpeedLimitsMode, TransferInfo};
+use crate::webseeds::{WebSeed, WebSeedAction};
+use crate::{QBittorrentClientTrait, Response, Status};
 use rogue_logging::Failure;
 
 /// Mock client for testing without live API calls
@@ -16,6 +36,54 @@ use rogue_logging::Failure;
 pub struct MockQBittorrentClient {
     get_torrents: Option<Response<Vec<Torrent>>>,
     add_torrents: Option<Response<bool>>,
+    get_categories: Option<Response<HashMap<String, Category>>>,
+    category_actions: Option<Response<bool>>,
+    get_tags: Option<Response<Vec<String>>>,
+    tag_actions: Option<Response<bool>>,
+    location_actions: Option<Response<bool>>,
+    recheck_actions: Option<Response<bool>>,
+    rename_actions: Option<Response<bool>>,
+    get_download_limits: Option<Response<HashMap<String, i64>>>,
+    get_upload_limits: Option<Response<HashMap<String, i64>>>,
+    limit_actions: Option<Response<bool>>,
+    flag_actions: Option<Response<bool>>,
+    flag_toggles: Option<Response<Vec<String>>>,
+    queue_actions: Option<Response<bool>>,
+    queue_reorder: Option<Response<Vec<String>>>,
+    torrent_peers: Option<Response<PeersUpdate>>,
+    peer_actions: Option<Response<bool>>,
+    piece_states: Option<Response<Vec<PieceState>>>,
+    piece_hashes: Option<Response<Vec<String>>>,
+    webseeds: Option<Response<Vec<WebSeed>>>,
+    webseed_actions: Option<Response<bool>>,
+    transfer_info: Option<Response<TransferInfo>>,
+    speed_limits_mode: Option<Response<SpeedLimitsMode>>,
+    transfer_actions: Option<Response<bool>>,
+    preferences: Option<Response<Preferences>>,
+    preference_actions: Option<Response<bool>>,
+    build_info: Option<Response<BuildInfo>>,
+    default_save_path: Option<Response<String>>,
+    shutdown: Option<Response<bool>>,
+    main_log: Option<Response<Vec<LogEntry>>>,
+    peer_log: Option<Response<Vec<PeerLogEntry>>>,
+    rss_items: Option<Response<RssFolder>>,
+    rss_actions: Option<Response<bool>>,
+    rss_rule_actions: Option<Response<bool>>,
+    rss_rules: Option<Response<HashMap<String, AutoDownloadRule>>>,
+    rss_matching_articles: Option<Response<HashMap<String, Vec<String>>>>,
+    search_id: Option<Response<u64>>,
+    search_status: Option<Response<Vec<SearchStatus>>>,
+    search_results: Option<Response<SearchResults>>,
+    search_actions: Option<Response<bool>>,
+    search_plugins: Option<Response<Vec<SearchPlugin>>>,
+    search_plugin_actions: Option<Response<bool>>,
+    add_torrent_creation_task: Option<Response<String>>,
+    torrent_creation_status: Option<Response<Vec<TorrentCreationStatus>>>,
+    torrent_creation_file: Option<Response<Vec<u8>>>,
+    delete_torrent_creation_task: Option<Response<bool>>,
+    directory_content: Option<Response<Vec<DirectoryEntry>>>,
+    login: Option<Status>,
+    logout: Option<Response<bool>>,
 }
 
 impl MockQBittorrentClient {
@@ -25,6 +93,54 @@ impl MockQBittorrentClient {
         Self {
             get_torrents: None,
             add_torrents: None,
+            get_categories: None,
+            category_actions: None,
+            get_tags: None,
+            tag_actions: None,
+            location_actions: None,
+            recheck_actions: None,
+            rename_actions: None,
+            get_download_limits: None,
+            get_upload_limits: None,
+            limit_actions: None,
+            flag_actions: None,
+            flag_toggles: None,
+            queue_actions: None,
+            queue_reorder: None,
+            torrent_peers: None,
+            peer_actions: None,
+            piece_states: None,
+            piece_hashes: None,
+            webseeds: None,
+            webseed_actions: None,
+            transfer_info: None,
+            speed_limits_mode: None,
+            transfer_actions: None,
+            preferences: None,
+            preference_actions: None,
+            build_info: None,
+            default_save_path: None,
+            shutdown: None,
+            main_log: None,
+            peer_log: None,
+            rss_items: None,
+            rss_actions: None,
+            rss_rule_actions: None,
+            rss_rules: None,
+            rss_matching_articles: None,
+            search_id: None,
+            search_status: None,
+            search_results: None,
+            search_actions: None,
+            search_plugins: None,
+            search_plugin_actions: None,
+            add_torrent_creation_task: None,
+            torrent_creation_status: None,
+            torrent_creation_file: None,
+            delete_torrent_creation_task: None,
+            directory_content: None,
+            login: None,
+            logout: None,
         }
     }
 
@@ -35,57 +151,1247 @@ impl MockQBittorrentClient {
         self
     }
 
-    /// Configure the return value for `add_torrent` and `add_torrents`
+    /// Configure the return value for `add_torrent`, `add_torrents` and `add_torrent_urls`
     #[must_use]
     pub fn with_add_torrents(mut self, response: Response<bool>) -> Self {
         self.add_torrents = Some(response);
         self
     }
+
+    /// Configure the return value for `get_categories`
+    #[must_use]
+    pub fn with_get_categories(mut self, response: Response<HashMap<String, Category>>) -> Self {
+        self.get_categories = Some(response);
+        self
+    }
+
+    /// Configure the return value for `create_category`, `edit_category`,
+    /// `remove_categories` and `set_torrent_category`
+    #[must_use]
+    pub fn with_category_actions(mut self, response: Response<bool>) -> Self {
+        self.category_actions = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_tags`
+    #[must_use]
+    pub fn with_get_tags(mut self, response: Response<Vec<String>>) -> Self {
+        self.get_tags = Some(response);
+        self
+    }
+
+    /// Configure the return value for `create_tags`, `delete_tags`,
+    /// `add_torrent_tags`, `remove_torrent_tags` and `set_torrent_tags`
+    #[must_use]
+    pub fn with_tag_actions(mut self, response: Response<bool>) -> Self {
+        self.tag_actions = Some(response);
+        self
+    }
+
+    /// Configure the return value for `set_location`, `set_save_path` and `set_download_path`
+    #[must_use]
+    pub fn with_location_actions(mut self, response: Response<bool>) -> Self {
+        self.location_actions = Some(response);
+        self
+    }
+
+    /// Configure the return value for `recheck_torrents` and `reannounce_torrents`
+    #[must_use]
+    pub fn with_recheck_actions(mut self, response: Response<bool>) -> Self {
+        self.recheck_actions = Some(response);
+        self
+    }
+
+    /// Configure the return value for `rename_torrent`, `rename_file` and `rename_folder`
+    #[must_use]
+    pub fn with_rename_actions(mut self, response: Response<bool>) -> Self {
+        self.rename_actions = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_download_limits`
+    #[must_use]
+    pub fn with_get_download_limits(mut self, response: Response<HashMap<String, i64>>) -> Self {
+        self.get_download_limits = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_upload_limits`
+    #[must_use]
+    pub fn with_get_upload_limits(mut self, response: Response<HashMap<String, i64>>) -> Self {
+        self.get_upload_limits = Some(response);
+        self
+    }
+
+    /// Configure the return value for `set_download_limit`, `set_upload_limit` and `set_share_limits`
+    #[must_use]
+    pub fn with_limit_actions(mut self, response: Response<bool>) -> Self {
+        self.limit_actions = Some(response);
+        self
+    }
+
+    /// Configure the return value for `set_force_start`, `set_super_seeding`,
+    /// `set_auto_management`, `toggle_sequential_download` and
+    /// `toggle_first_last_piece_priority`
+    #[must_use]
+    pub fn with_flag_actions(mut self, response: Response<bool>) -> Self {
+        self.flag_actions = Some(response);
+        self
+    }
+
+    /// Configure the return value for `set_sequential_download` and
+    /// `set_first_last_piece_priority`
+    #[must_use]
+    pub fn with_flag_toggles(mut self, response: Response<Vec<String>>) -> Self {
+        self.flag_toggles = Some(response);
+        self
+    }
+
+    /// Configure the return value for `increase_priority`, `decrease_priority`,
+    /// `top_priority` and `bottom_priority`
+    #[must_use]
+    pub fn with_queue_actions(mut self, response: Response<bool>) -> Self {
+        self.queue_actions = Some(response);
+        self
+    }
+
+    /// Configure the return value for `reorder_queue`
+    #[must_use]
+    pub fn with_queue_reorder(mut self, response: Response<Vec<String>>) -> Self {
+        self.queue_reorder = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_torrent_peers`
+    #[must_use]
+    pub fn with_torrent_peers(mut self, response: Response<PeersUpdate>) -> Self {
+        self.torrent_peers = Some(response);
+        self
+    }
+
+    /// Configure the return value for `add_peers` and `ban_peers`
+    #[must_use]
+    pub fn with_peer_actions(mut self, response: Response<bool>) -> Self {
+        self.peer_actions = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_piece_states`
+    #[must_use]
+    pub fn with_piece_states(mut self, response: Response<Vec<PieceState>>) -> Self {
+        self.piece_states = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_piece_hashes`
+    #[must_use]
+    pub fn with_piece_hashes(mut self, response: Response<Vec<String>>) -> Self {
+        self.piece_hashes = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_webseeds`
+    #[must_use]
+    pub fn with_webseeds(mut self, response: Response<Vec<WebSeed>>) -> Self {
+        self.webseeds = Some(response);
+        self
+    }
+
+    /// Configure the return value for `add_webseeds`, `edit_webseed` and `remove_webseeds`
+    #[must_use]
+    pub fn with_webseed_actions(mut self, response: Response<bool>) -> Self {
+        self.webseed_actions = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_transfer_info`
+    #[must_use]
+    pub fn with_transfer_info(mut self, response: Response<TransferInfo>) -> Self {
+        self.transfer_info = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_speed_limits_mode`
+    #[must_use]
+    pub fn with_speed_limits_mode(mut self, response: Response<SpeedLimitsMode>) -> Self {
+        self.speed_limits_mode = Some(response);
+        self
+    }
+
+    /// Configure the return value for `set_global_download_limit`,
+    /// `set_global_upload_limit`, `toggle_speed_limits_mode` and `set_speed_limits_mode`
+    #[must_use]
+    pub fn with_transfer_actions(mut self, response: Response<bool>) -> Self {
+        self.transfer_actions = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_preferences`
+    #[must_use]
+    pub fn with_preferences(mut self, response: Response<Preferences>) -> Self {
+        self.preferences = Some(response);
+        self
+    }
+
+    /// Configure the return value for `set_preferences`
+    #[must_use]
+    pub fn with_preference_actions(mut self, response: Response<bool>) -> Self {
+        self.preference_actions = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_build_info`
+    #[must_use]
+    pub fn with_build_info(mut self, response: Response<BuildInfo>) -> Self {
+        self.build_info = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_default_save_path`
+    #[must_use]
+    pub fn with_default_save_path(mut self, response: Response<String>) -> Self {
+        self.default_save_path = Some(response);
+        self
+    }
+
+    /// Configure the return value for `shutdown`
+    #[must_use]
+    pub fn with_shutdown(mut self, response: Response<bool>) -> Self {
+        self.shutdown = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_main_log`
+    #[must_use]
+    pub fn with_main_log(mut self, response: Response<Vec<LogEntry>>) -> Self {
+        self.main_log = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_peer_log`
+    #[must_use]
+    pub fn with_peer_log(mut self, response: Response<Vec<PeerLogEntry>>) -> Self {
+        self.peer_log = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_rss_items`
+    #[must_use]
+    pub fn with_rss_items(mut self, response: Response<RssFolder>) -> Self {
+        self.rss_items = Some(response);
+        self
+    }
+
+    /// Configure the return value for RSS folder and feed actions
+    #[must_use]
+    pub fn with_rss_actions(mut self, response: Response<bool>) -> Self {
+        self.rss_actions = Some(response);
+        self
+    }
+
+    /// Configure the return value for RSS auto-download rule actions
+    #[must_use]
+    pub fn with_rss_rule_actions(mut self, response: Response<bool>) -> Self {
+        self.rss_rule_actions = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_rss_rules`
+    #[must_use]
+    pub fn with_rss_rules(mut self, response: Response<HashMap<String, AutoDownloadRule>>) -> Self {
+        self.rss_rules = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_rss_matching_articles`
+    #[must_use]
+    pub fn with_rss_matching_articles(
+        mut self,
+        response: Response<HashMap<String, Vec<String>>>,
+    ) -> Self {
+        self.rss_matching_articles = Some(response);
+        self
+    }
+
+    /// Configure the return value for `start_search`
+    #[must_use]
+    pub fn with_search_id(mut self, response: Response<u64>) -> Self {
+        self.search_id = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_search_status`
+    #[must_use]
+    pub fn with_search_status(mut self, response: Response<Vec<SearchStatus>>) -> Self {
+        self.search_status = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_search_results`
+    #[must_use]
+    pub fn with_search_results(mut self, response: Response<SearchResults>) -> Self {
+        self.search_results = Some(response);
+        self
+    }
+
+    /// Configure the return value for `stop_search` and `delete_search`
+    #[must_use]
+    pub fn with_search_actions(mut self, response: Response<bool>) -> Self {
+        self.search_actions = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_search_plugins`
+    #[must_use]
+    pub fn with_search_plugins(mut self, response: Response<Vec<SearchPlugin>>) -> Self {
+        self.search_plugins = Some(response);
+        self
+    }
+
+    /// Configure the return value for search plugin actions
+    #[must_use]
+    pub fn with_search_plugin_actions(mut self, response: Response<bool>) -> Self {
+        self.search_plugin_actions = Some(response);
+        self
+    }
+
+    /// Configure the return value for `add_torrent_creation_task`
+    #[must_use]
+    pub fn with_add_torrent_creation_task(mut self, response: Response<String>) -> Self {
+        self.add_torrent_creation_task = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_torrent_creation_status`
+    #[must_use]
+    pub fn with_torrent_creation_status(
+        mut self,
+        response: Response<Vec<TorrentCreationStatus>>,
+    ) -> Self {
+        self.torrent_creation_status = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_torrent_creation_file`
+    #[must_use]
+    pub fn with_torrent_creation_file(mut self, response: Response<Vec<u8>>) -> Self {
+        self.torrent_creation_file = Some(response);
+        self
+    }
+
+    /// Configure the return value for `delete_torrent_creation_task`
+    #[must_use]
+    pub fn with_delete_torrent_creation_task(mut self, response: Response<bool>) -> Self {
+        self.delete_torrent_creation_task = Some(response);
+        self
+    }
+
+    /// Configure the return value for `get_directory_content`
+    #[must_use]
+    pub fn with_directory_content(mut self, response: Response<Vec<DirectoryEntry>>) -> Self {
+        self.directory_content = Some(response);
+        self
+    }
+
+    /// Configure the status returned by `login`, `ensure_login` fails unless it is `Success`
+    #[must_use]
+    pub fn with_login(mut self, response: Status) -> Self {
+        self.login = Some(response);
+        self
+    }
+
+    /// Configure the return value for `logout`
+    #[must_use]
+    pub fn with_logout(mut self, response: Response<bool>) -> Self {
+        self.logout = Some(response);
+        self
+    }
 }
 
 impl Default for MockQBittorrentClient {
     fn default() -> Self {
         Self {
-            get_torrents: Some(Response {
-                status_code: Some(200),
-                result: Some(vec![Torrent::mock()]),
-            }),
-            add_torrents: Some(Response {
-                status_code: Some(200),
-                result: Some(true),
-            }),
+            get_torrents: Some(ok(vec![Torrent::mock()])),
+            add_torrents: Some(ok(true)),
+            get_categories: Some(ok(HashMap::from([(
+                Category::mock().name,
+                Category::mock(),
+            )]))),
+            category_actions: Some(ok(true)),
+            get_tags: Some(ok(vec!["new".to_owned()])),
+            tag_actions: Some(ok(true)),
+            location_actions: Some(ok(true)),
+            recheck_actions: Some(ok(true)),
+            rename_actions: Some(ok(true)),
+            get_download_limits: Some(ok(HashMap::from([(Torrent::mock().hash, 0)]))),
+            get_upload_limits: Some(ok(HashMap::from([(Torrent::mock().hash, 0)]))),
+            limit_actions: Some(ok(true)),
+            flag_actions: Some(ok(true)),
+            flag_toggles: Some(ok(Vec::new())),
+            queue_actions: Some(ok(true)),
+            queue_reorder: Some(ok(Vec::new())),
+            torrent_peers: Some(ok(PeersUpdate::default())),
+            peer_actions: Some(ok(true)),
+            piece_states: Some(ok(Vec::new())),
+            piece_hashes: Some(ok(Vec::new())),
+            webseeds: Some(ok(Vec::new())),
+            webseed_actions: Some(ok(true)),
+            transfer_info: Some(ok(TransferInfo::mock())),
+            speed_limits_mode: Some(ok(SpeedLimitsMode::Global)),
+            transfer_actions: Some(ok(true)),
+            preferences: Some(ok(Preferences::mock())),
+            preference_actions: Some(ok(true)),
+            build_info: Some(ok(BuildInfo::mock())),
+            default_save_path: Some(ok("/downloads".to_owned())),
+            shutdown: Some(ok(true)),
+            main_log: Some(ok(Vec::new())),
+            peer_log: Some(ok(Vec::new())),
+            rss_items: Some(ok(RssFolder::default())),
+            rss_actions: Some(ok(true)),
+            rss_rule_actions: Some(ok(true)),
+            rss_rules: Some(ok(HashMap::new())),
+            rss_matching_articles: Some(ok(HashMap::new())),
+            search_id: Some(ok(1)),
+            search_status: Some(ok(Vec::new())),
+            search_results: Some(ok(SearchResults::mock())),
+            search_actions: Some(ok(true)),
+            search_plugins: Some(ok(Vec::new())),
+            search_plugin_actions: Some(ok(true)),
+            add_torrent_creation_task: Some(ok(String::new())),
+            torrent_creation_status: Some(ok(Vec::new())),
+            torrent_creation_file: Some(ok(Vec::new())),
+            delete_torrent_creation_task: Some(ok(true)),
+            directory_content: Some(ok(Vec::new())),
+            login: Some(Status::Success),
+            logout: Some(ok(true)),
         }
     }
-}
-
-#[async_trait]
-impl QBittorrentClientTrait for MockQBittorrentClient {
-    async fn get_torrents(
+}
+
+/// Successful response with `result`.
+fn ok<T>(result: T) -> Response<T> {
+    Response {
+        status_code: Some(200),
+        result: Some(result),
+    }
+}
+
+#[async_trait]
+impl QBittorrentClientTrait for MockQBittorrentClient {
+    async fn get_torrents(
+        &self,
+        _filters: FilterOptions,
+    ) -> Result<Response<Vec<Torrent>>, Failure<ClientAction>> {
+        Ok(self
+            .get_torrents
+            .clone()
+            .expect("MockQBittorrentClient: get_torrents not set"))
+    }
+    async fn add_torrent(
+        &self,
+        options: AddTorrentOptions,
+        torrent: PathBuf,
+    ) -> Result<Response<bool>, Failure<AddTorrentAction>> {
+        self.add_torrents(options, vec![torrent]).await
+    }
+    async fn add_torrents(
+        &self,
+        _options: AddTorrentOptions,
+        _torrents: Vec<PathBuf>,
+    ) -> Result<Response<bool>, Failure<AddTorrentAction>> {
+        Ok(self
+            .add_torrents
+            .clone()
+            .expect("MockQBittorrentClient: add_torrents not set"))
+    }
+    async fn get_categories(
+        &self,
+    ) -> Result<Response<HashMap<String, Category>>, Failure<ClientAction>> {
+        Ok(self
+            .get_categories
+            .clone()
+            .expect("MockQBittorrentClient: get_categories not set"))
+    }
+    async fn create_category(
+        &self,
+        _category: Category,
+    ) -> Result<Response<bool>, Failure<CategoryAction>> {
+        Ok(self
+            .category_actions
+            .clone()
+            .expect("MockQBittorrentClient: category_actions not set"))
+    }
+    async fn edit_category(
+        &self,
+        _category: Category,
+    ) -> Result<Response<bool>, Failure<CategoryAction>> {
+        Ok(self
+            .category_actions
+            .clone()
+            .expect("MockQBittorrentClient: category_actions not set"))
+    }
+    async fn remove_categories(
+        &self,
+        _names: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .category_actions
+            .clone()
+            .expect("MockQBittorrentClient: category_actions not set"))
+    }
+    async fn set_torrent_category(
+        &self,
+        _hashes: Vec<String>,
+        _name: String,
+    ) -> Result<Response<bool>, Failure<CategoryAction>> {
+        Ok(self
+            .category_actions
+            .clone()
+            .expect("MockQBittorrentClient: category_actions not set"))
+    }
+    async fn get_tags(&self) -> Result<Response<Vec<String>>, Failure<ClientAction>> {
+        Ok(self
+            .get_tags
+            .clone()
+            .expect("MockQBittorrentClient: get_tags not set"))
+    }
+    async fn create_tags(
+        &self,
+        _tags: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .tag_actions
+            .clone()
+            .expect("MockQBittorrentClient: tag_actions not set"))
+    }
+    async fn delete_tags(
+        &self,
+        _tags: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .tag_actions
+            .clone()
+            .expect("MockQBittorrentClient: tag_actions not set"))
+    }
+    async fn add_torrent_tags(
+        &self,
+        _hashes: Vec<String>,
+        _tags: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .tag_actions
+            .clone()
+            .expect("MockQBittorrentClient: tag_actions not set"))
+    }
+    async fn remove_torrent_tags(
+        &self,
+        _hashes: Vec<String>,
+        _tags: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .tag_actions
+            .clone()
+            .expect("MockQBittorrentClient: tag_actions not set"))
+    }
+    async fn set_torrent_tags(
+        &self,
+        _hashes: Vec<String>,
+        _tags: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .tag_actions
+            .clone()
+            .expect("MockQBittorrentClient: tag_actions not set"))
+    }
+    async fn set_location(
+        &self,
+        _hashes: Vec<String>,
+        _path: String,
+    ) -> Result<Response<bool>, Failure<LocationAction>> {
+        Ok(self
+            .location_actions
+            .clone()
+            .expect("MockQBittorrentClient: location_actions not set"))
+    }
+    async fn set_save_path(
+        &self,
+        _hashes: Vec<String>,
+        _path: String,
+    ) -> Result<Response<bool>, Failure<LocationAction>> {
+        Ok(self
+            .location_actions
+            .clone()
+            .expect("MockQBittorrentClient: location_actions not set"))
+    }
+    async fn set_download_path(
+        &self,
+        _hashes: Vec<String>,
+        _path: String,
+    ) -> Result<Response<bool>, Failure<LocationAction>> {
+        Ok(self
+            .location_actions
+            .clone()
+            .expect("MockQBittorrentClient: location_actions not set"))
+    }
+    async fn recheck_torrents(
+        &self,
+        _hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .recheck_actions
+            .clone()
+            .expect("MockQBittorrentClient: recheck_actions not set"))
+    }
+    async fn reannounce_torrents(
+        &self,
+        _hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .recheck_actions
+            .clone()
+            .expect("MockQBittorrentClient: recheck_actions not set"))
+    }
+    async fn rename_torrent(
+        &self,
+        _hash: String,
+        _name: String,
+    ) -> Result<Response<bool>, Failure<RenameAction>> {
+        Ok(self
+            .rename_actions
+            .clone()
+            .expect("MockQBittorrentClient: rename_actions not set"))
+    }
+    async fn rename_file(
+        &self,
+        _hash: String,
+        _old_path: String,
+        _new_path: String,
+    ) -> Result<Response<bool>, Failure<RenameAction>> {
+        Ok(self
+            .rename_actions
+            .clone()
+            .expect("MockQBittorrentClient: rename_actions not set"))
+    }
+    async fn rename_folder(
+        &self,
+        _hash: String,
+        _old_path: String,
+        _new_path: String,
+    ) -> Result<Response<bool>, Failure<RenameAction>> {
+        Ok(self
+            .rename_actions
+            .clone()
+            .expect("MockQBittorrentClient: rename_actions not set"))
+    }
+    async fn get_download_limits(
+        &self,
+        _hashes: Vec<String>,
+    ) -> Result<Response<HashMap<String, i64>>, Failure<ClientAction>> {
+        Ok(self
+            .get_download_limits
+            .clone()
+            .expect("MockQBittorrentClient: get_download_limits not set"))
+    }
+    async fn set_download_limit(
+        &self,
+        _hashes: Vec<String>,
+        _limit: u64,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .limit_actions
+            .clone()
+            .expect("MockQBittorrentClient: limit_actions not set"))
+    }
+    async fn get_upload_limits(
+        &self,
+        _hashes: Vec<String>,
+    ) -> Result<Response<HashMap<String, i64>>, Failure<ClientAction>> {
+        Ok(self
+            .get_upload_limits
+            .clone()
+            .expect("MockQBittorrentClient: get_upload_limits not set"))
+    }
+    async fn set_upload_limit(
+        &self,
+        _hashes: Vec<String>,
+        _limit: u64,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .limit_actions
+            .clone()
+            .expect("MockQBittorrentClient: limit_actions not set"))
+    }
+    async fn set_share_limits(
+        &self,
+        _hashes: Vec<String>,
+        _limits: ShareLimits,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .limit_actions
+            .clone()
+            .expect("MockQBittorrentClient: limit_actions not set"))
+    }
+    async fn set_force_start(
+        &self,
+        _hashes: Vec<String>,
+        _enabled: bool,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .flag_actions
+            .clone()
+            .expect("MockQBittorrentClient: flag_actions not set"))
+    }
+    async fn set_super_seeding(
+        &self,
+        _hashes: Vec<String>,
+        _enabled: bool,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .flag_actions
+            .clone()
+            .expect("MockQBittorrentClient: flag_actions not set"))
+    }
+    async fn set_auto_management(
+        &self,
+        _hashes: Vec<String>,
+        _enabled: bool,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .flag_actions
+            .clone()
+            .expect("MockQBittorrentClient: flag_actions not set"))
+    }
+    async fn toggle_sequential_download(
+        &self,
+        _hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .flag_actions
+            .clone()
+            .expect("MockQBittorrentClient: flag_actions not set"))
+    }
+    async fn toggle_first_last_piece_priority(
+        &self,
+        _hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .flag_actions
+            .clone()
+            .expect("MockQBittorrentClient: flag_actions not set"))
+    }
+    async fn set_sequential_download(
+        &self,
+        _hashes: Vec<String>,
+        _enabled: bool,
+    ) -> Result<Response<Vec<String>>, Failure<ClientAction>> {
+        Ok(self
+            .flag_toggles
+            .clone()
+            .expect("MockQBittorrentClient: flag_toggles not set"))
+    }
+    async fn set_first_last_piece_priority(
+        &self,
+        _hashes: Vec<String>,
+        _enabled: bool,
+    ) -> Result<Response<Vec<String>>, Failure<ClientAction>> {
+        Ok(self
+            .flag_toggles
+            .clone()
+            .expect("MockQBittorrentClient: flag_toggles not set"))
+    }
+    async fn increase_priority(
+        &self,
+        _hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<QueueAction>> {
+        Ok(self
+            .queue_actions
+            .clone()
+            .expect("MockQBittorrentClient: queue_actions not set"))
+    }
+    async fn decrease_priority(
+        &self,
+        _hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<QueueAction>> {
+        Ok(self
+            .queue_actions
+            .clone()
+            .expect("MockQBittorrentClient: queue_actions not set"))
+    }
+    async fn top_priority(
+        &self,
+        _hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<QueueAction>> {
+        Ok(self
+            .queue_actions
+            .clone()
+            .expect("MockQBittorrentClient: queue_actions not set"))
+    }
+    async fn bottom_priority(
+        &self,
+        _hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<QueueAction>> {
+        Ok(self
+            .queue_actions
+            .clone()
+            .expect("MockQBittorrentClient: queue_actions not set"))
+    }
+    async fn reorder_queue(
         &self,
-        _filters: FilterOptions,
-    ) -> Result<Response<Vec<Torrent>>, Failure<ClientAction>> {
+        _hashes: Vec<String>,
+    ) -> Result<Response<Vec<String>>, Failure<QueueAction>> {
         Ok(self
-            .get_torrents
+            .queue_reorder
             .clone()
-            .expect("MockQBittorrentClient: get_torrents not set"))
+            .expect("MockQBittorrentClient: queue_reorder not set"))
     }
-    async fn add_torrent(
+    async fn get_torrent_peers(
         &self,
-        options: AddTorrentOptions,
-        torrent: PathBuf,
-    ) -> Result<Response<bool>, Failure<AddTorrentAction>> {
-        self.add_torrents(options, vec![torrent]).await
+        _hash: String,
+        _rid: u64,
+    ) -> Result<Response<PeersUpdate>, Failure<PeerAction>> {
+        Ok(self
+            .torrent_peers
+            .clone()
+            .expect("MockQBittorrentClient: torrent_peers not set"))
     }
-    async fn add_torrents(
+    async fn add_peers(
+        &self,
+        _hashes: Vec<String>,
+        _peers: Vec<PeerAddr>,
+    ) -> Result<Response<bool>, Failure<PeerAction>> {
+        Ok(self
+            .peer_actions
+            .clone()
+            .expect("MockQBittorrentClient: peer_actions not set"))
+    }
+    async fn ban_peers(
+        &self,
+        _peers: Vec<PeerAddr>,
+    ) -> Result<Response<bool>, Failure<PeerAction>> {
+        Ok(self
+            .peer_actions
+            .clone()
+            .expect("MockQBittorrentClient: peer_actions not set"))
+    }
+    async fn get_piece_states(
+        &self,
+        _hash: String,
+    ) -> Result<Response<Vec<PieceState>>, Failure<PieceAction>> {
+        Ok(self
+            .piece_states
+            .clone()
+            .expect("MockQBittorrentClient: piece_states not set"))
+    }
+    async fn get_piece_hashes(
+        &self,
+        _hash: String,
+    ) -> Result<Response<Vec<String>>, Failure<PieceAction>> {
+        Ok(self
+            .piece_hashes
+            .clone()
+            .expect("MockQBittorrentClient: piece_hashes not set"))
+    }
+    async fn get_webseeds(
+        &self,
+        _hash: String,
+    ) -> Result<Response<Vec<WebSeed>>, Failure<WebSeedAction>> {
+        Ok(self
+            .webseeds
+            .clone()
+            .expect("MockQBittorrentClient: webseeds not set"))
+    }
+    async fn add_webseeds(
+        &self,
+        _hash: String,
+        _urls: Vec<String>,
+    ) -> Result<Response<bool>, Failure<WebSeedAction>> {
+        Ok(self
+            .webseed_actions
+            .clone()
+            .expect("MockQBittorrentClient: webseed_actions not set"))
+    }
+    async fn edit_webseed(
+        &self,
+        _hash: String,
+        _old_url: String,
+        _new_url: String,
+    ) -> Result<Response<bool>, Failure<WebSeedAction>> {
+        Ok(self
+            .webseed_actions
+            .clone()
+            .expect("MockQBittorrentClient: webseed_actions not set"))
+    }
+    async fn remove_webseeds(
+        &self,
+        _hash: String,
+        _urls: Vec<String>,
+    ) -> Result<Response<bool>, Failure<WebSeedAction>> {
+        Ok(self
+            .webseed_actions
+            .clone()
+            .expect("MockQBittorrentClient: webseed_actions not set"))
+    }
+    async fn get_transfer_info(&self) -> Result<Response<TransferInfo>, Failure<ClientAction>> {
+        Ok(self
+            .transfer_info
+            .clone()
+            .expect("MockQBittorrentClient: transfer_info not set"))
+    }
+    async fn set_global_download_limit(
+        &self,
+        _limit: u64,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .transfer_actions
+            .clone()
+            .expect("MockQBittorrentClient: transfer_actions not set"))
+    }
+    async fn set_global_upload_limit(
+        &self,
+        _limit: u64,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .transfer_actions
+            .clone()
+            .expect("MockQBittorrentClient: transfer_actions not set"))
+    }
+    async fn get_speed_limits_mode(
+        &self,
+    ) -> Result<Response<SpeedLimitsMode>, Failure<ClientAction>> {
+        Ok(self
+            .speed_limits_mode
+            .clone()
+            .expect("MockQBittorrentClient: speed_limits_mode not set"))
+    }
+    async fn toggle_speed_limits_mode(&self) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .transfer_actions
+            .clone()
+            .expect("MockQBittorrentClient: transfer_actions not set"))
+    }
+    async fn set_speed_limits_mode(
+        &self,
+        _mode: SpeedLimitsMode,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .transfer_actions
+            .clone()
+            .expect("MockQBittorrentClient: transfer_actions not set"))
+    }
+    async fn get_preferences(&self) -> Result<Response<Preferences>, Failure<ClientAction>> {
+        Ok(self
+            .preferences
+            .clone()
+            .expect("MockQBittorrentClient: preferences not set"))
+    }
+    async fn set_preferences(
+        &self,
+        _patch: PreferencesPatch,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .preference_actions
+            .clone()
+            .expect("MockQBittorrentClient: preference_actions not set"))
+    }
+    async fn get_build_info(&self) -> Result<Response<BuildInfo>, Failure<ClientAction>> {
+        Ok(self
+            .build_info
+            .clone()
+            .expect("MockQBittorrentClient: build_info not set"))
+    }
+    async fn get_default_save_path(&self) -> Result<Response<String>, Failure<ClientAction>> {
+        Ok(self
+            .default_save_path
+            .clone()
+            .expect("MockQBittorrentClient: default_save_path not set"))
+    }
+    async fn shutdown(&self) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .shutdown
+            .clone()
+            .expect("MockQBittorrentClient: shutdown not set"))
+    }
+    async fn get_main_log(
+        &self,
+        _filter: LogFilter,
+    ) -> Result<Response<Vec<LogEntry>>, Failure<ClientAction>> {
+        Ok(self
+            .main_log
+            .clone()
+            .expect("MockQBittorrentClient: main_log not set"))
+    }
+    async fn get_peer_log(
+        &self,
+        _last_known_id: Option<i64>,
+    ) -> Result<Response<Vec<PeerLogEntry>>, Failure<ClientAction>> {
+        Ok(self
+            .peer_log
+            .clone()
+            .expect("MockQBittorrentClient: peer_log not set"))
+    }
+    async fn get_rss_items(
+        &self,
+        _with_data: bool,
+    ) -> Result<Response<RssFolder>, Failure<RssAction>> {
+        Ok(self
+            .rss_items
+            .clone()
+            .expect("MockQBittorrentClient: rss_items not set"))
+    }
+    async fn add_rss_folder(&self, _path: String) -> Result<Response<bool>, Failure<RssAction>> {
+        Ok(self
+            .rss_actions
+            .clone()
+            .expect("MockQBittorrentClient: rss_actions not set"))
+    }
+    async fn add_rss_feed(
+        &self,
+        _url: String,
+        _path: Option<String>,
+    ) -> Result<Response<bool>, Failure<RssAction>> {
+        Ok(self
+            .rss_actions
+            .clone()
+            .expect("MockQBittorrentClient: rss_actions not set"))
+    }
+    async fn remove_rss_item(&self, _path: String) -> Result<Response<bool>, Failure<RssAction>> {
+        Ok(self
+            .rss_actions
+            .clone()
+            .expect("MockQBittorrentClient: rss_actions not set"))
+    }
+    async fn move_rss_item(
+        &self,
+        _path: String,
+        _destination: String,
+    ) -> Result<Response<bool>, Failure<RssAction>> {
+        Ok(self
+            .rss_actions
+            .clone()
+            .expect("MockQBittorrentClient: rss_actions not set"))
+    }
+    async fn refresh_rss_item(&self, _path: String) -> Result<Response<bool>, Failure<RssAction>> {
+        Ok(self
+            .rss_actions
+            .clone()
+            .expect("MockQBittorrentClient: rss_actions not set"))
+    }
+    async fn mark_rss_as_read(
+        &self,
+        _path: String,
+        _article_id: Option<String>,
+    ) -> Result<Response<bool>, Failure<RssAction>> {
+        Ok(self
+            .rss_actions
+            .clone()
+            .expect("MockQBittorrentClient: rss_actions not set"))
+    }
+    async fn set_rss_rule(
+        &self,
+        _name: String,
+        _rule: AutoDownloadRule,
+    ) -> Result<Response<bool>, Failure<RssAction>> {
+        Ok(self
+            .rss_rule_actions
+            .clone()
+            .expect("MockQBittorrentClient: rss_rule_actions not set"))
+    }
+    async fn rename_rss_rule(
+        &self,
+        _name: String,
+        _new_name: String,
+    ) -> Result<Response<bool>, Failure<RssAction>> {
+        Ok(self
+            .rss_rule_actions
+            .clone()
+            .expect("MockQBittorrentClient: rss_rule_actions not set"))
+    }
+    async fn remove_rss_rule(&self, _name: String) -> Result<Response<bool>, Failure<RssAction>> {
+        Ok(self
+            .rss_rule_actions
+            .clone()
+            .expect("MockQBittorrentClient: rss_rule_actions not set"))
+    }
+    async fn get_rss_rules(
+        &self,
+    ) -> Result<Response<HashMap<String, AutoDownloadRule>>, Failure<RssAction>> {
+        Ok(self
+            .rss_rules
+            .clone()
+            .expect("MockQBittorrentClient: rss_rules not set"))
+    }
+    async fn get_rss_matching_articles(
+        &self,
+        _name: String,
+    ) -> Result<Response<HashMap<String, Vec<String>>>, Failure<RssAction>> {
+        Ok(self
+            .rss_matching_articles
+            .clone()
+            .expect("MockQBittorrentClient: rss_matching_articles not set"))
+    }
+    async fn add_torrent_urls(
         &self,
         _options: AddTorrentOptions,
-        _torrents: Vec<PathBuf>,
+        _urls: Vec<String>,
     ) -> Result<Response<bool>, Failure<AddTorrentAction>> {
         Ok(self
             .add_torrents
             .clone()
             .expect("MockQBittorrentClient: add_torrents not set"))
     }
+    async fn start_search(
+        &self,
+        _query: SearchQuery,
+    ) -> Result<Response<u64>, Failure<SearchAction>> {
+        Ok(self
+            .search_id
+            .clone()
+            .expect("MockQBittorrentClient: search_id not set"))
+    }
+    async fn get_search_status(
+        &self,
+        _id: Option<u64>,
+    ) -> Result<Response<Vec<SearchStatus>>, Failure<SearchAction>> {
+        Ok(self
+            .search_status
+            .clone()
+            .expect("MockQBittorrentClient: search_status not set"))
+    }
+    async fn get_search_results(
+        &self,
+        _id: u64,
+        _offset: usize,
+        _limit: Option<usize>,
+    ) -> Result<Response<SearchResults>, Failure<SearchAction>> {
+        Ok(self
+            .search_results
+            .clone()
+            .expect("MockQBittorrentClient: search_results not set"))
+    }
+    async fn stop_search(&self, _id: u64) -> Result<Response<bool>, Failure<SearchAction>> {
+        Ok(self
+            .search_actions
+            .clone()
+            .expect("MockQBittorrentClient: search_actions not set"))
+    }
+    async fn delete_search(&self, _id: u64) -> Result<Response<bool>, Failure<SearchAction>> {
+        Ok(self
+            .search_actions
+            .clone()
+            .expect("MockQBittorrentClient: search_actions not set"))
+    }
+    async fn get_search_plugins(
+        &self,
+    ) -> Result<Response<Vec<SearchPlugin>>, Failure<SearchAction>> {
+        Ok(self
+            .search_plugins
+            .clone()
+            .expect("MockQBittorrentClient: search_plugins not set"))
+    }
+    async fn install_search_plugin(
+        &self,
+        _sources: Vec<String>,
+    ) -> Result<Response<bool>, Failure<SearchAction>> {
+        Ok(self
+            .search_plugin_actions
+            .clone()
+            .expect("MockQBittorrentClient: search_plugin_actions not set"))
+    }
+    async fn uninstall_search_plugin(
+        &self,
+        _names: Vec<String>,
+    ) -> Result<Response<bool>, Failure<SearchAction>> {
+        Ok(self
+            .search_plugin_actions
+            .clone()
+            .expect("MockQBittorrentClient: search_plugin_actions not set"))
+    }
+    async fn enable_search_plugin(
+        &self,
+        _names: Vec<String>,
+        _enable: bool,
+    ) -> Result<Response<bool>, Failure<SearchAction>> {
+        Ok(self
+            .search_plugin_actions
+            .clone()
+            .expect("MockQBittorrentClient: search_plugin_actions not set"))
+    }
+    async fn update_search_plugins(&self) -> Result<Response<bool>, Failure<SearchAction>> {
+        Ok(self
+            .search_plugin_actions
+            .clone()
+            .expect("MockQBittorrentClient: search_plugin_actions not set"))
+    }
+    async fn add_torrent_creation_task(
+        &self,
+        _task: TorrentCreationTask,
+    ) -> Result<Response<String>, Failure<TorrentCreatorAction>> {
+        Ok(self
+            .add_torrent_creation_task
+            .clone()
+            .expect("MockQBittorrentClient: add_torrent_creation_task not set"))
+    }
+    async fn get_torrent_creation_status(
+        &self,
+        _task_id: Option<String>,
+    ) -> Result<Response<Vec<TorrentCreationStatus>>, Failure<TorrentCreatorAction>> {
+        Ok(self
+            .torrent_creation_status
+            .clone()
+            .expect("MockQBittorrentClient: torrent_creation_status not set"))
+    }
+    async fn get_torrent_creation_file(
+        &self,
+        _task_id: String,
+    ) -> Result<Response<Vec<u8>>, Failure<TorrentCreatorAction>> {
+        Ok(self
+            .torrent_creation_file
+            .clone()
+            .expect("MockQBittorrentClient: torrent_creation_file not set"))
+    }
+    async fn delete_torrent_creation_task(
+        &self,
+        _task_id: String,
+    ) -> Result<Response<bool>, Failure<TorrentCreatorAction>> {
+        Ok(self
+            .delete_torrent_creation_task
+            .clone()
+            .expect("MockQBittorrentClient: delete_torrent_creation_task not set"))
+    }
+    async fn get_directory_content(
+        &self,
+        _path: String,
+        _mode: DirectoryContentMode,
+    ) -> Result<Response<Vec<DirectoryEntry>>, Failure<DirectoryAction>> {
+        Ok(self
+            .directory_content
+            .clone()
+            .expect("MockQBittorrentClient: directory_content not set"))
+    }
+    async fn login(&self) -> Result<Status, Failure<ClientAction>> {
+        Ok(self
+            .login
+            .clone()
+            .expect("MockQBittorrentClient: login not set"))
+    }
+    async fn ensure_login(&self) -> Result<(), Failure<ClientAction>> {
+        let status = self
+            .login
+            .clone()
+            .expect("MockQBittorrentClient: login not set");
+        if status != Status::Success {
+            return Err(
+                Failure::from_action(ClientAction::Login).with("status", format!("{status:?}"))
+            );
+        }
+        Ok(())
+    }
+    async fn logout(&self) -> Result<Response<bool>, Failure<ClientAction>> {
+        Ok(self
+            .logout
+            .clone()
+            .expect("MockQBittorrentClient: logout not set"))
+    }
 }
 
 #[cfg(test)]
@@ -106,6 +1412,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[expect(clippy::too_many_lines, reason = "one assertion per trait method")]
     async fn mock_default_has_all_ok_responses() {
         let mock = MockQBittorrentClient::default();
         assert!(mock.get_torrents(FilterOptions::default()).await.is_ok());
@@ -114,6 +1421,109 @@ mod tests {
                 .await
                 .is_ok()
         );
+        assert!(mock.get_categories().await.is_ok());
+        assert!(
+            mock.set_torrent_category(vec![], String::new())
+                .await
+                .is_ok()
+        );
+        assert!(mock.get_tags().await.is_ok());
+        assert!(mock.add_torrent_tags(vec![], vec![]).await.is_ok());
+        assert!(mock.set_location(vec![], String::new()).await.is_ok());
+        assert!(mock.recheck_torrents(vec![]).await.is_ok());
+        assert!(
+            mock.rename_torrent(String::new(), String::new())
+                .await
+                .is_ok()
+        );
+        assert!(mock.get_download_limits(vec![]).await.is_ok());
+        assert!(
+            mock.set_share_limits(vec![], ShareLimits::default())
+                .await
+                .is_ok()
+        );
+        assert!(mock.set_force_start(vec![], true).await.is_ok());
+        assert!(mock.set_sequential_download(vec![], true).await.is_ok());
+        assert!(mock.top_priority(vec![]).await.is_ok());
+        assert!(mock.reorder_queue(vec![]).await.is_ok());
+        assert!(mock.get_torrent_peers(String::new(), 0).await.is_ok());
+        assert!(mock.ban_peers(vec![]).await.is_ok());
+        assert!(mock.get_piece_states(String::new()).await.is_ok());
+        assert!(mock.get_piece_hashes(String::new()).await.is_ok());
+        assert!(mock.get_webseeds(String::new()).await.is_ok());
+        assert!(mock.add_webseeds(String::new(), vec![]).await.is_ok());
+        assert!(mock.get_transfer_info().await.is_ok());
+        assert!(
+            mock.set_speed_limits_mode(SpeedLimitsMode::Alternative)
+                .await
+                .is_ok()
+        );
+        assert!(mock.get_preferences().await.is_ok());
+        assert!(mock.set_preferences(PreferencesPatch::new()).await.is_ok());
+        assert!(mock.get_build_info().await.is_ok());
+        assert!(mock.get_default_save_path().await.is_ok());
+        assert!(mock.shutdown().await.is_ok());
+        assert!(mock.get_main_log(LogFilter::default()).await.is_ok());
+        assert!(mock.get_peer_log(None).await.is_ok());
+        assert!(mock.get_rss_items(false).await.is_ok());
+        assert!(mock.add_rss_folder(String::new()).await.is_ok());
+        assert!(mock.add_rss_feed(String::new(), None).await.is_ok());
+        assert!(mock.remove_rss_item(String::new()).await.is_ok());
+        assert!(
+            mock.move_rss_item(String::new(), String::new())
+                .await
+                .is_ok()
+        );
+        assert!(mock.refresh_rss_item(String::new()).await.is_ok());
+        assert!(mock.mark_rss_as_read(String::new(), None).await.is_ok());
+        assert!(
+            mock.set_rss_rule(String::new(), AutoDownloadRule::default())
+                .await
+                .is_ok()
+        );
+        assert!(
+            mock.rename_rss_rule(String::new(), String::new())
+                .await
+                .is_ok()
+        );
+        assert!(mock.remove_rss_rule(String::new()).await.is_ok());
+        assert!(mock.get_rss_rules().await.is_ok());
+        assert!(mock.get_rss_matching_articles(String::new()).await.is_ok());
+        assert!(
+            mock.add_torrent_urls(AddTorrentOptions::default(), Vec::new())
+                .await
+                .is_ok()
+        );
+        assert!(mock.start_search(SearchQuery::default()).await.is_ok());
+        assert!(mock.get_search_status(None).await.is_ok());
+        assert!(mock.get_search_results(1, 0, None).await.is_ok());
+        assert!(mock.stop_search(1).await.is_ok());
+        assert!(mock.delete_search(1).await.is_ok());
+        assert!(mock.get_search_plugins().await.is_ok());
+        assert!(mock.install_search_plugin(Vec::new()).await.is_ok());
+        assert!(mock.uninstall_search_plugin(Vec::new()).await.is_ok());
+        assert!(mock.enable_search_plugin(Vec::new(), true).await.is_ok());
+        assert!(mock.update_search_plugins().await.is_ok());
+        assert!(
+            mock.add_torrent_creation_task(TorrentCreationTask::new("/downloads"))
+                .await
+                .is_ok()
+        );
+        assert!(mock.get_torrent_creation_status(None).await.is_ok());
+        assert!(mock.get_torrent_creation_file(String::new()).await.is_ok());
+        assert!(
+            mock.delete_torrent_creation_task(String::new())
+                .await
+                .is_ok()
+        );
+        assert!(
+            mock.get_directory_content(String::new(), DirectoryContentMode::All)
+                .await
+                .is_ok()
+        );
+        assert!(mock.login().await.is_ok());
+        assert!(mock.ensure_login().await.is_ok());
+        assert!(mock.logout().await.is_ok());
     }
 
     #[tokio::test]
@@ -126,4 +1536,14 @@ mod tests {
         assert!(result1.is_ok());
         assert!(result2.is_ok());
     }
+
+    #[tokio::test]
+    async fn mock_ensure_login_fails_on_rejected_login() {
+        let mock = MockQBittorrentClient::default().with_login(Status::Failure);
+        let error = mock
+            .ensure_login()
+            .await
+            .expect_err("rejected login should fail");
+        assert_eq!(error.action(), &ClientAction::Login);
+    }
 }
@@ -1,5 +1,7 @@
 //! Configuration options for connecting to the qBittorrent `WebUI` API.
 
+use std::path::PathBuf;
+
 use serde::{Deserialize, Serialize};
 
 /// Connection options for the qBittorrent `WebUI` API.
@@ -28,4 +30,9 @@ pub struct QBittorrentClientOptions {
 
     /// Duration in seconds before rate limit is reset.
     pub rate_limit_duration: Option<usize>,
+
+    /// Path of a file to persist the session cookie in so it is reused across restarts.
+    ///
+    /// See [`FileSessionStore`](crate::session_store::FileSessionStore).
+    pub session_file: Option<PathBuf>,
 }
@@ -0,0 +1,278 @@
+//! Rule-based banning of peers by client name and flags.
+
+use std::collections::BTreeSet;
+
+use crate::QBittorrentClient;
+use crate::peers::{Peer, PeerAction, PeerAddr, PeerSync};
+use colored::Colorize;
+use log::{debug, warn};
+use rogue_logging::Failure;
+use serde::{Deserialize, Serialize};
+
+impl QBittorrentClient {
+    /// Ban the peers of torrents that match any of the rules
+    ///
+    /// - Peers are fetched with a full `/sync/torrentPeers` update for each torrent.
+    /// - Peers are banned with a single `/transfer/banPeers` request unless
+    ///   [`PeerBanOptions::dry_run`] is set.
+    /// - Peers with an invalid address are logged and skipped.
+    ///
+    /// Returns the matching peers in the order of `hashes`.
+    pub async fn ban_matching_peers(
+        &self,
+        hashes: Vec<String>,
+        options: PeerBanOptions,
+    ) -> Result<Vec<PeerBan>, Failure<PeerAction>> {
+        let mut bans = Vec::new();
+        for hash in hashes {
+            let mut sync = PeerSync::new(hash.clone());
+            self.sync_peers(&mut sync).await?;
+            let mut peers: Vec<&Peer> = sync.peers().values().collect();
+            peers.sort_by(|a, b| (&a.ip, a.port).cmp(&(&b.ip, b.port)));
+            for peer in peers {
+                let Some(rule) = get_matching_rule(&options.rules, peer) else {
+                    continue;
+                };
+                let addr = match peer.addr() {
+                    Ok(addr) => addr,
+                    Err(e) => {
+                        warn!("{e}");
+                        continue;
+                    }
+                };
+                bans.push(PeerBan {
+                    hash: hash.clone(),
+                    addr,
+                    client: peer.client.clone(),
+                    rule: rule.name.clone(),
+                });
+            }
+        }
+        let addrs: BTreeSet<PeerAddr> = bans.iter().map(|ban| ban.addr).collect();
+        if options.dry_run || addrs.is_empty() {
+            for ban in &bans {
+                debug!(
+                    "{} {} ({}) matching rule {}",
+                    "Would ban".bold(),
+                    ban.addr,
+                    ban.client,
+                    ban.rule
+                );
+            }
+            return Ok(bans);
+        }
+        debug!("{} {} peers", "Banning".bold(), addrs.len());
+        self.ban_peers(addrs.into_iter().collect())
+            .await?
+            .get_result("ban_peers")
+            .map_err(Failure::wrap(PeerAction::Request))?;
+        Ok(bans)
+    }
+}
+
+/// Options for [`QBittorrentClient::ban_matching_peers`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PeerBanOptions {
+    /// Peers matching any of these rules are banned.
+    pub rules: Vec<PeerBanRule>,
+
+    /// Report the matching peers without banning them.
+    pub dry_run: bool,
+}
+
+/// A rule matching peers to ban.
+///
+/// A peer matches if it matches any of the `clients` patterns and has all of the `flags`.
+/// An empty list is ignored, but a rule with neither clients nor flags matches nothing.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PeerBanRule {
+    /// Name of the rule to report in [`PeerBan::rule`].
+    pub name: String,
+
+    /// Case-insensitive client name patterns where `*` matches any characters.
+    ///
+    /// Matched against both [`Peer::client`] and [`Peer::peer_id_client`].
+    pub clients: Vec<String>,
+
+    /// Peer flags such as `D` or `X` that must all be set.
+    pub flags: Vec<String>,
+}
+
+impl PeerBanRule {
+    /// Whether the peer matches the rule.
+    #[must_use]
+    pub fn is_match(&self, peer: &Peer) -> bool {
+        if self.clients.is_empty() && self.flags.is_empty() {
+            return false;
+        }
+        let client_matches = self.clients.is_empty()
+            || self.clients.iter().any(|pattern| {
+                is_pattern_match(pattern, &peer.client)
+                    || peer
+                        .peer_id_client
+                        .as_ref()
+                        .is_some_and(|client| is_pattern_match(pattern, client))
+            });
+        let flags_match = self.flags.iter().all(|flag| {
+            peer.flags
+                .split_whitespace()
+                .any(|peer_flag| peer_flag == flag)
+        });
+        client_matches && flags_match
+    }
+}
+
+/// A peer matched by [`QBittorrentClient::ban_matching_peers`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PeerBan {
+    /// Hash of the torrent the peer was connected to.
+    pub hash: String,
+    /// Address of the peer.
+    pub addr: PeerAddr,
+    /// Client name reported by the peer.
+    pub client: String,
+    /// Name of the first matching rule.
+    pub rule: String,
+}
+
+/// First rule that matches the peer.
+fn get_matching_rule<'a>(rules: &'a [PeerBanRule], peer: &Peer) -> Option<&'a PeerBanRule> {
+    rules.iter().find(|rule| rule.is_match(peer))
+}
+
+/// Case-insensitive match where `*` in `pattern` matches any characters.
+fn is_pattern_match(pattern: &str, value: &str) -> bool {
+    let pattern = pattern.to_lowercase();
+    let value = value.to_lowercase();
+    let mut parts = pattern.split('*');
+    let Some(first) = parts.next() else {
+        return value.is_empty();
+    };
+    let Some(mut remaining) = value.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<&str> = parts.collect();
+    let Some(last) = parts.pop() else {
+        return remaining.is_empty();
+    };
+    for part in parts {
+        let Some(index) = remaining.find(part) else {
+            return false;
+        };
+        remaining = remaining.get(index + part.len()..).unwrap_or_default();
+    }
+    remaining.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::peers::PeersUpdate;
+
+    const FIXTURE_V5: &str = include_str!("tests/fixtures/sync_torrent_peers_v5.json");
+
+    fn fixture_peers() -> Vec<Peer> {
+        let update: PeersUpdate =
+            serde_json::from_str(FIXTURE_V5).expect("fixture should deserialize");
+        let mut sync = PeerSync::new(String::new());
+        sync.apply(update).expect("update should apply");
+        let mut peers: Vec<Peer> = sync.peers().values().cloned().collect();
+        peers.sort_by(|a, b| a.client.cmp(&b.client));
+        peers
+    }
+
+    fn rule(clients: &[&str], flags: &[&str]) -> PeerBanRule {
+        PeerBanRule {
+            name: "test".to_owned(),
+            clients: clients.iter().map(|client| (*client).to_owned()).collect(),
+            flags: flags.iter().map(|flag| (*flag).to_owned()).collect(),
+        }
+    }
+
+    #[test]
+    fn pattern_match() {
+        assert!(is_pattern_match("Transmission*", "Transmission 4.0.5"));
+        assert!(is_pattern_match("transmission*", "Transmission 4.0.5"));
+        assert!(is_pattern_match("*4.0*", "Transmission 4.0.5"));
+        assert!(is_pattern_match("*", "anything"));
+        assert!(is_pattern_match("T*ion*5", "Transmission 4.0.5"));
+        assert!(is_pattern_match("-TR4050-", "-tr4050-"));
+        assert!(!is_pattern_match("Transmission", "Transmission 4.0.5"));
+        assert!(!is_pattern_match("*Xunlei*", "Transmission 4.0.5"));
+        assert!(!is_pattern_match("T*ss*ss*", "Transmission"));
+    }
+
+    #[test]
+    fn rule_matches_client() {
+        let peers = fixture_peers();
+        let matches: Vec<&str> = peers
+            .iter()
+            .filter(|peer| rule(&["transmission*"], &[]).is_match(peer))
+            .map(|peer| peer.ip.as_str())
+            .collect();
+        assert_eq!(matches, vec!["203.0.113.10"]);
+    }
+
+    #[test]
+    fn rule_matches_peer_id_client() {
+        let peers = fixture_peers();
+        let matches = peers
+            .iter()
+            .filter(|peer| rule(&["-qB5*"], &[]).is_match(peer))
+            .count();
+        assert_eq!(matches, 1);
+    }
+
+    #[test]
+    fn rule_requires_all_flags() {
+        let peers = fixture_peers();
+        let matches: Vec<&str> = peers
+            .iter()
+            .filter(|peer| rule(&[], &["I", "X"]).is_match(peer))
+            .map(|peer| peer.ip.as_str())
+            .collect();
+        assert_eq!(matches, vec!["203.0.113.10"]);
+        let matches = peers
+            .iter()
+            .filter(|peer| rule(&[], &["I", "E"]).is_match(peer))
+            .count();
+        assert_eq!(matches, 0);
+    }
+
+    #[test]
+    fn rule_matches_client_and_flags() {
+        let peers = fixture_peers();
+        let matches = peers
+            .iter()
+            .filter(|peer| rule(&["qBittorrent*"], &["X"]).is_match(peer))
+            .count();
+        assert_eq!(matches, 0);
+    }
+
+    #[test]
+    fn empty_rule_matches_nothing() {
+        let peers = fixture_peers();
+        assert!(peers.iter().all(|peer| !rule(&[], &[]).is_match(peer)));
+    }
+
+    #[test]
+    fn first_matching_rule() {
+        let peers = fixture_peers();
+        let rules = vec![
+            PeerBanRule {
+                name: "first".to_owned(),
+                ..rule(&["*"], &["E"])
+            },
+            PeerBanRule {
+                name: "second".to_owned(),
+                ..rule(&["*"], &[])
+            },
+        ];
+        let names: Vec<&str> = peers
+            .iter()
+            .filter_map(|peer| get_matching_rule(&rules, peer))
+            .map(|rule| rule.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["second", "first"]);
+    }
+}
@@ -0,0 +1,564 @@
+//! Torrent peers and incremental peer sync.
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::net::{IpAddr, SocketAddr};
+use std::str::FromStr;
+
+use crate::client::{deserialize_response, join_hashes, success_response};
+use crate::{QBittorrentClient, Response};
+use reqwest::Method;
+use rogue_logging::Failure;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+impl QBittorrentClient {
+    /// Get the peer changes of a torrent since response id `rid`
+    ///
+    /// A `rid` of `0`, or one the server no longer recognises, returns a full update.
+    ///
+    /// Prefer [`sync_peers`](Self::sync_peers) to keep a merged list of peers.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-torrent-peers-data>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-torrent-peers-data>
+    pub async fn get_torrent_peers(
+        &self,
+        hash: String,
+        rid: u64,
+    ) -> Result<Response<PeersUpdate>, Failure<PeerAction>> {
+        let method = Method::GET;
+        let endpoint = "/sync/torrentPeers";
+        let query = vec![("hash", hash), ("rid", rid.to_string())];
+        let response = self
+            .request_with_login(method.clone(), endpoint, &query)
+            .await
+            .map_err(Failure::wrap(PeerAction::Request))?;
+        if response.status().as_u16() == 404 {
+            return Err(Failure::from_action(PeerAction::TorrentNotFound)
+                .with("endpoint", endpoint)
+                .with("status_code", "404"));
+        }
+        deserialize_response::<PeersUpdate>(&method, endpoint, response)
+            .await
+            .map_err(Failure::wrap(PeerAction::Request))
+    }
+
+    /// Add peers to torrents
+    ///
+    /// Returns [`PeerAction::InvalidPeers`] if none of the peers could be added.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#add-peers>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#add-peers>
+    pub async fn add_peers(
+        &self,
+        hashes: Vec<String>,
+        peers: Vec<PeerAddr>,
+    ) -> Result<Response<bool>, Failure<PeerAction>> {
+        let endpoint = "/torrents/addPeers";
+        let data = vec![
+            ("hashes", join_hashes(&hashes)),
+            ("peers", join_peers(&peers)),
+        ];
+        let response = self
+            .request_with_login(Method::POST, endpoint, &data)
+            .await
+            .map_err(Failure::wrap(PeerAction::Request))?;
+        if response.status().as_u16() == 400 {
+            return Err(Failure::from_action(PeerAction::InvalidPeers)
+                .with("endpoint", endpoint)
+                .with("status_code", "400"));
+        }
+        Ok(success_response(&response))
+    }
+
+    /// Ban peers from connecting to any torrent
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#ban-peers>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#ban-peers>
+    pub async fn ban_peers(
+        &self,
+        peers: Vec<PeerAddr>,
+    ) -> Result<Response<bool>, Failure<PeerAction>> {
+        let data = vec![("peers", join_peers(&peers))];
+        let response = self
+            .request_with_login(Method::POST, "/transfer/banPeers", &data)
+            .await
+            .map_err(Failure::wrap(PeerAction::Request))?;
+        Ok(success_response(&response))
+    }
+
+    /// Fetch the peer changes since the last sync and merge them into `peers`
+    pub async fn sync_peers(&self, peers: &mut PeerSync) -> Result<(), Failure<PeerAction>> {
+        let update = self
+            .get_torrent_peers(peers.hash.clone(), peers.rid)
+            .await?
+            .get_result("get_torrent_peers")
+            .map_err(Failure::wrap(PeerAction::Request))?;
+        peers.apply(update)
+    }
+}
+
+/// Peer changes returned by `/sync/torrentPeers`.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-torrent-peers-data>
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/webui/api/synccontroller.cpp>
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct PeersUpdate {
+    /// Response id to send with the next request.
+    pub rid: u64,
+
+    /// Whether `peers` replaces all previously known peers.
+    #[serde(default)]
+    pub full_update: bool,
+
+    /// Changed peers keyed by `ip:port`.
+    ///
+    /// Only the fields that changed are included unless this is a full update.
+    #[serde(default)]
+    pub peers: HashMap<String, Map<String, Value>>,
+
+    /// Keys of peers that disconnected.
+    #[serde(default)]
+    pub peers_removed: Vec<String>,
+
+    /// Whether peer flags are shown.
+    pub show_flags: Option<bool>,
+}
+
+/// Peers of a single torrent kept up to date by
+/// [`QBittorrentClient::sync_peers`](crate::QBittorrentClient::sync_peers).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PeerSync {
+    hash: String,
+    rid: u64,
+    peers: HashMap<String, Peer>,
+}
+
+impl PeerSync {
+    /// Create an empty sync for the torrent with `hash`.
+    #[must_use]
+    pub fn new(hash: String) -> Self {
+        Self {
+            hash,
+            ..Self::default()
+        }
+    }
+
+    /// Torrent hash.
+    #[must_use]
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    /// Response id of the last applied update.
+    #[must_use]
+    pub fn rid(&self) -> u64 {
+        self.rid
+    }
+
+    /// Current peers keyed by `ip:port`.
+    #[must_use]
+    pub fn peers(&self) -> &HashMap<String, Peer> {
+        &self.peers
+    }
+
+    /// Merge an update into the current peers.
+    ///
+    /// Partial peer data is overlaid on the known peer before it is deserialized.
+    pub fn apply(&mut self, update: PeersUpdate) -> Result<(), Failure<PeerAction>> {
+        if update.full_update {
+            self.peers.clear();
+        }
+        for key in &update.peers_removed {
+            self.peers.remove(key);
+        }
+        for (key, changes) in update.peers {
+            let peer = merge_peer(self.peers.get(&key), changes)
+                .map_err(|e| Failure::new(PeerAction::MergePeer, e).with("peer", &key))?;
+            self.peers.insert(key, peer);
+        }
+        self.rid = update.rid;
+        Ok(())
+    }
+}
+
+/// Join peer addresses with `|` as expected by the API.
+fn join_peers(peers: &[PeerAddr]) -> String {
+    peers
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("|")
+}
+
+/// Overlay the changed fields of a peer on its last known state.
+fn merge_peer(peer: Option<&Peer>, changes: Map<String, Value>) -> Result<Peer, serde_json::Error> {
+    let mut fields = match peer.map(serde_json::to_value).transpose()? {
+        Some(Value::Object(fields)) => fields,
+        _ => Map::new(),
+    };
+    fields.extend(changes);
+    serde_json::from_value(Value::Object(fields))
+}
+
+/// A peer connected to a torrent.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-torrent-peers-data>
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/webui/api/synccontroller.cpp>
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Peer {
+    /// IP address.
+    pub ip: String,
+
+    /// Port.
+    pub port: u16,
+
+    /// Client name and version reported by the peer.
+    pub client: String,
+
+    /// Client identified from the peer id.
+    ///
+    /// Not returned by older servers.
+    pub peer_id_client: Option<String>,
+
+    /// Connection type.
+    pub connection: ConnectionType,
+
+    /// Space separated peer flags such as `D`, `u` and `X`.
+    pub flags: String,
+
+    /// Description of each flag in `flags`, one per line.
+    pub flags_desc: String,
+
+    /// Country name.
+    ///
+    /// Only returned if peer country resolution is enabled.
+    pub country: Option<String>,
+
+    /// Two letter lowercase country code.
+    ///
+    /// Only returned if peer country resolution is enabled.
+    pub country_code: Option<String>,
+
+    /// Peer progress (percentage/100).
+    pub progress: f64,
+
+    /// Download speed from the peer (bytes/s).
+    pub dl_speed: i64,
+
+    /// Upload speed to the peer (bytes/s).
+    pub up_speed: i64,
+
+    /// Amount of data downloaded from the peer (bytes).
+    pub downloaded: i64,
+
+    /// Amount of data uploaded to the peer (bytes).
+    pub uploaded: i64,
+
+    /// Fraction of the pieces we need that the peer has (percentage/100).
+    pub relevance: f64,
+
+    /// Files the peer is currently transferring, one per line.
+    pub files: String,
+}
+
+impl Peer {
+    /// Whether the peer has not yet downloaded the whole torrent.
+    #[must_use]
+    pub fn is_leecher(&self) -> bool {
+        self.progress < 1.0
+    }
+
+    /// Address of the peer.
+    pub fn addr(&self) -> Result<PeerAddr, Failure<PeerAction>> {
+        let ip = IpAddr::from_str(&self.ip)
+            .map_err(|e| Failure::new(PeerAction::InvalidAddress, e).with("ip", self.ip.clone()))?;
+        PeerAddr::new(ip, self.port)
+    }
+}
+
+/// A validated peer address.
+///
+/// Formatted as `ip:port` for IPv4 and `[ip]:port` for IPv6, as expected by the API.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct PeerAddr(SocketAddr);
+
+impl PeerAddr {
+    /// Create from an IP address and port.
+    ///
+    /// Returns [`PeerAction::InvalidAddress`] if `port` is 0.
+    pub fn new(ip: IpAddr, port: u16) -> Result<Self, Failure<PeerAction>> {
+        if port == 0 {
+            return Err(Failure::from_action(PeerAction::InvalidAddress)
+                .with("ip", ip.to_string())
+                .with("reason", "port must not be 0"));
+        }
+        Ok(Self(SocketAddr::new(ip, port)))
+    }
+
+    /// IP address.
+    #[must_use]
+    pub fn ip(&self) -> IpAddr {
+        self.0.ip()
+    }
+
+    /// Port.
+    #[must_use]
+    pub fn port(&self) -> u16 {
+        self.0.port()
+    }
+}
+
+impl FromStr for PeerAddr {
+    type Err = Failure<PeerAction>;
+
+    /// Parse `ip:port` or `[ip]:port`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let addr = SocketAddr::from_str(value)
+            .map_err(|e| Failure::new(PeerAction::InvalidAddress, e).with("address", value))?;
+        Self::new(addr.ip(), addr.port()).map_err(|failure| failure.with("address", value))
+    }
+}
+
+impl TryFrom<String> for PeerAddr {
+    type Error = Failure<PeerAction>;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_str(&value)
+    }
+}
+
+impl From<PeerAddr> for String {
+    fn from(addr: PeerAddr) -> Self {
+        addr.to_string()
+    }
+}
+
+impl Display for PeerAddr {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        write!(formatter, "{}", self.0)
+    }
+}
+
+/// How a peer is connected.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/base/bittorrent/peerinfo.cpp>
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ConnectionType {
+    /// TCP peer connection.
+    #[serde(rename = "BT")]
+    BitTorrent,
+
+    /// uTP peer connection.
+    #[serde(rename = "μTP", alias = "uTP")]
+    Utp,
+
+    /// HTTP web seed.
+    #[serde(rename = "Web")]
+    Web,
+
+    /// Connection type not known to this library.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Errors returned by peer operations
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Error)]
+pub enum PeerAction {
+    #[error("send request")]
+    Request,
+    #[error("find torrent")]
+    TorrentNotFound,
+    #[error("merge peer update")]
+    MergePeer,
+    #[error("parse peer address")]
+    InvalidAddress,
+    #[error("add any of the peers")]
+    InvalidPeers,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QBittorrentClientOptions;
+    use crate::get_torrents::FilterOptions;
+    use crate::tests::init_logger;
+    use insta::assert_yaml_snapshot;
+    use log::trace;
+    use rogue_config::{OptionsProvider, YamlOptionsProvider};
+    use std::collections::BTreeMap;
+    use std::error::Error;
+
+    const FIXTURE_V5: &str = include_str!("tests/fixtures/sync_torrent_peers_v5.json");
+    const FIXTURE_V5_PARTIAL: &str =
+        include_str!("tests/fixtures/sync_torrent_peers_v5_partial.json");
+    const IPV4_PEER: &str = "203.0.113.10:51413";
+    const IPV6_PEER: &str = "[2001:db8::1]:6881";
+    const WEB_PEER: &str = "198.51.100.7:40000";
+
+    fn apply_fixture(sync: &mut PeerSync, fixture: &str) {
+        let update: PeersUpdate =
+            serde_json::from_str(fixture).expect("fixture should deserialize");
+        sync.apply(update).expect("update should apply");
+    }
+
+    #[tokio::test]
+    #[ignore = "integration test requiring API credentials"]
+    async fn sync_peers() -> Result<(), Box<dyn Error>> {
+        // Arrange
+        init_logger();
+        let options: QBittorrentClientOptions =
+            YamlOptionsProvider::get().map_err(|e| e.to_string())?;
+        let client = QBittorrentClient::from_options(options);
+        let torrents = client
+            .get_torrents(FilterOptions::default())
+            .await?
+            .get_result("get_torrents")?;
+        let torrent = torrents.first().ok_or("no torrents")?;
+        let mut sync = PeerSync::new(torrent.hash.clone());
+
+        // Act
+        client.sync_peers(&mut sync).await?;
+        client.sync_peers(&mut sync).await?;
+        trace!("{:#?}", sync.peers());
+
+        // Assert
+        assert!(sync.rid() > 0);
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_v5_fixture() {
+        let mut sync = PeerSync::new(String::new());
+        apply_fixture(&mut sync, FIXTURE_V5);
+        let peers: BTreeMap<_, _> = sync.peers().iter().collect();
+        assert_yaml_snapshot!(peers);
+    }
+
+    #[test]
+    fn apply_partial_update() {
+        // Arrange
+        let mut sync = PeerSync::new(String::new());
+        apply_fixture(&mut sync, FIXTURE_V5);
+
+        // Act
+        apply_fixture(&mut sync, FIXTURE_V5_PARTIAL);
+
+        // Assert
+        assert_eq!(sync.rid(), 2);
+        assert_eq!(sync.peers().len(), 2);
+        assert!(!sync.peers().contains_key(IPV6_PEER));
+        let peer = sync.peers().get(IPV4_PEER).expect("peer should be merged");
+        assert!((peer.progress - 0.5).abs() < f64::EPSILON);
+        assert_eq!(peer.up_speed, 0);
+        assert_eq!(peer.uploaded, 104_857_600);
+        assert_eq!(peer.client, "Transmission 4.0.5");
+        let peer = sync.peers().get(WEB_PEER).expect("peer should be added");
+        assert_eq!(peer.connection, ConnectionType::Web);
+        assert!(peer.is_leecher());
+    }
+
+    #[test]
+    fn apply_full_update_replaces_peers() {
+        // Arrange
+        let mut sync = PeerSync::new(String::new());
+        apply_fixture(&mut sync, FIXTURE_V5);
+        apply_fixture(&mut sync, FIXTURE_V5_PARTIAL);
+        assert!(sync.peers().contains_key(WEB_PEER));
+
+        // Act
+        apply_fixture(&mut sync, FIXTURE_V5);
+
+        // Assert
+        assert!(!sync.peers().contains_key(WEB_PEER));
+        assert_eq!(sync.peers().len(), 2);
+    }
+
+    #[test]
+    fn apply_partial_update_for_unknown_peer() {
+        let mut sync = PeerSync::new(String::new());
+        let update: PeersUpdate =
+            serde_json::from_str(r#"{"rid": 3, "peers": {"192.0.2.1:1": {"progress": 1}}}"#)
+                .expect("update should deserialize");
+        let error = sync.apply(update).expect_err("incomplete peer should fail");
+        assert_eq!(error.action(), &PeerAction::MergePeer);
+    }
+
+    #[test]
+    fn parse_peer_addr() {
+        for (value, expected) in [
+            ("203.0.113.10:51413", "203.0.113.10:51413"),
+            ("[2001:db8::1]:6881", "[2001:db8::1]:6881"),
+            ("[2001:DB8:0::1]:6881", "[2001:db8::1]:6881"),
+        ] {
+            let addr = PeerAddr::from_str(value).expect("address should parse");
+            assert_eq!(addr.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn parse_peer_addr_invalid() {
+        for value in [
+            "203.0.113.10",
+            "2001:db8::1:6881",
+            "203.0.113.10:0",
+            "203.0.113.10:65536",
+            "example.com:6881",
+            "",
+        ] {
+            let error = PeerAddr::from_str(value).expect_err("address should be invalid");
+            assert_eq!(error.action(), &PeerAction::InvalidAddress);
+        }
+    }
+
+    #[test]
+    fn new_peer_addr_rejects_port_zero() {
+        let ip = IpAddr::from_str("203.0.113.10").expect("ip should parse");
+        let error = PeerAddr::new(ip, 0).expect_err("port 0 should be invalid");
+        assert_eq!(error.action(), &PeerAction::InvalidAddress);
+        let addr = PeerAddr::new(ip, 6881).expect("address should be valid");
+        assert_eq!(addr.to_string(), "203.0.113.10:6881");
+    }
+
+    #[test]
+    fn peer_addr_from_fixture() {
+        let mut sync = PeerSync::new(String::new());
+        apply_fixture(&mut sync, FIXTURE_V5);
+        for (key, peer) in sync.peers() {
+            let addr = peer.addr().expect("fixture address should be valid");
+            assert_eq!(&addr.to_string(), key);
+        }
+    }
+
+    #[test]
+    fn join_peers_with_pipe() {
+        let peers = vec![
+            PeerAddr::from_str("203.0.113.10:51413").expect("address should parse"),
+            PeerAddr::from_str("[2001:db8::1]:6881").expect("address should parse"),
+        ];
+        assert_eq!(join_peers(&peers), "203.0.113.10:51413|[2001:db8::1]:6881");
+    }
+
+    #[test]
+    fn deserialize_connection_type() {
+        for (json, expected) in [
+            (r#""BT""#, ConnectionType::BitTorrent),
+            (r#""μTP""#, ConnectionType::Utp),
+            (r#""uTP""#, ConnectionType::Utp),
+            (r#""Web""#, ConnectionType::Web),
+            (r#""I2P""#, ConnectionType::Unknown),
+        ] {
+            let connection: ConnectionType =
+                serde_json::from_str(json).expect("connection type should deserialize");
+            assert_eq!(connection, expected);
+        }
+    }
+}
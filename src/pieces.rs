@@ -0,0 +1,212 @@
+//! Torrent piece states and hashes, and helpers to summarize piece progress.
+
+use std::ops::Range;
+
+use crate::client::deserialize_response;
+use crate::{QBittorrentClient, Response};
+use reqwest::Method;
+use rogue_logging::Failure;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+impl QBittorrentClient {
+    /// Get the download state of each piece of a torrent
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-torrent-pieces-states>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-torrent-pieces-states>
+    pub async fn get_piece_states(
+        &self,
+        hash: String,
+    ) -> Result<Response<Vec<PieceState>>, Failure<PieceAction>> {
+        self.get_pieces("/torrents/pieceStates", hash).await
+    }
+
+    /// Get the SHA-1 hash of each piece of a torrent as lowercase hex
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-torrent-pieces-hashes>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-torrent-pieces-hashes>
+    pub async fn get_piece_hashes(
+        &self,
+        hash: String,
+    ) -> Result<Response<Vec<String>>, Failure<PieceAction>> {
+        self.get_pieces("/torrents/pieceHashes", hash).await
+    }
+
+    async fn get_pieces<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        hash: String,
+    ) -> Result<Response<Vec<T>>, Failure<PieceAction>> {
+        let method = Method::GET;
+        let query = vec![("hash", hash)];
+        let response = self
+            .request_with_login(method.clone(), endpoint, &query)
+            .await
+            .map_err(Failure::wrap(PieceAction::Request))?;
+        if response.status().as_u16() == 404 {
+            return Err(Failure::from_action(PieceAction::TorrentNotFound)
+                .with("endpoint", endpoint)
+                .with("status_code", "404"));
+        }
+        deserialize_response::<Vec<T>>(&method, endpoint, response)
+            .await
+            .map_err(Failure::wrap(PieceAction::Request))
+    }
+}
+
+/// Download state of a piece.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-torrent-pieces-states>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub enum PieceState {
+    /// Piece has not been downloaded.
+    NotDownloaded,
+    /// Piece is being downloaded.
+    Downloading,
+    /// Piece has been downloaded.
+    Downloaded,
+}
+
+impl TryFrom<u8> for PieceState {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(PieceState::NotDownloaded),
+            1 => Ok(PieceState::Downloading),
+            2 => Ok(PieceState::Downloaded),
+            _ => Err(format!("unknown piece state: {value}")),
+        }
+    }
+}
+
+impl From<PieceState> for u8 {
+    fn from(state: PieceState) -> Self {
+        match state {
+            PieceState::NotDownloaded => 0,
+            PieceState::Downloading => 1,
+            PieceState::Downloaded => 2,
+        }
+    }
+}
+
+/// Contiguous ranges of piece indexes that are in `state`.
+///
+/// Use [`PieceState::NotDownloaded`] to find the pieces holding a torrent back from completion.
+#[must_use]
+pub fn get_piece_ranges(states: &[PieceState], state: PieceState) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for (index, _) in states.iter().enumerate().filter(|(_, s)| **s == state) {
+        match ranges.last_mut() {
+            Some(range) if range.end == index => range.end = index + 1,
+            _ => ranges.push(index..index + 1),
+        }
+    }
+    ranges
+}
+
+/// Render piece states as a text progress bar of at most `width` characters.
+///
+/// Each character summarizes an equal share of the pieces:
+/// - `#` all pieces are downloaded
+/// - `>` at least one piece is downloading
+/// - `+` some pieces are downloaded
+/// - `.` no pieces are downloaded
+#[must_use]
+#[expect(
+    clippy::integer_division,
+    reason = "columns cover whole pieces so the bounds round down"
+)]
+pub fn get_piece_bar(states: &[PieceState], width: usize) -> String {
+    let width = width.min(states.len());
+    (0..width)
+        .map(|column| {
+            let start = column * states.len() / width;
+            let end = (column + 1) * states.len() / width;
+            get_piece_bar_char(states.get(start..end).unwrap_or_default())
+        })
+        .collect()
+}
+
+fn get_piece_bar_char(states: &[PieceState]) -> char {
+    if states.iter().all(|state| *state == PieceState::Downloaded) {
+        '#'
+    } else if states.contains(&PieceState::Downloading) {
+        '>'
+    } else if states.contains(&PieceState::Downloaded) {
+        '+'
+    } else {
+        '.'
+    }
+}
+
+/// Errors returned by piece operations
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Error)]
+pub enum PieceAction {
+    #[error("send request")]
+    Request,
+    #[error("find torrent")]
+    TorrentNotFound,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PieceState::*;
+
+    fn states(value: &str) -> Vec<PieceState> {
+        value
+            .chars()
+            .map(|c| match c {
+                '#' => Downloaded,
+                '>' => Downloading,
+                _ => NotDownloaded,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn deserialize_piece_states() {
+        let states: Vec<PieceState> =
+            serde_json::from_str("[2, 2, 1, 0]").expect("piece states should deserialize");
+        assert_eq!(
+            states,
+            vec![Downloaded, Downloaded, Downloading, NotDownloaded]
+        );
+        assert!(serde_json::from_str::<Vec<PieceState>>("[3]").is_err());
+    }
+
+    #[test]
+    fn piece_ranges() {
+        let states = states("##.>###..#");
+        assert_eq!(
+            get_piece_ranges(&states, Downloaded),
+            vec![0..2, 4..7, 9..10]
+        );
+        assert_eq!(get_piece_ranges(&states, NotDownloaded), vec![2..3, 7..9]);
+        assert_eq!(get_piece_ranges(&states, Downloading), vec![3..4]);
+        assert!(get_piece_ranges(&[], Downloaded).is_empty());
+    }
+
+    #[test]
+    fn piece_bar_one_char_per_piece() {
+        assert_eq!(get_piece_bar(&states("##.>#"), 10), "##.>#");
+    }
+
+    #[test]
+    fn piece_bar_summarizes_pieces() {
+        let states = states("####.#..>.");
+        assert_eq!(get_piece_bar(&states, 5), "##+.>");
+    }
+
+    #[test]
+    fn piece_bar_empty() {
+        assert_eq!(get_piece_bar(&[], 10), "");
+        assert_eq!(get_piece_bar(&states("##"), 0), "");
+    }
+}
@@ -0,0 +1,508 @@
+//! Application preferences.
+
+use std::collections::HashMap;
+
+use crate::client::{ClientAction, deserialize_response, success_response};
+use crate::{QBittorrentClient, Response};
+use reqwest::Method;
+use rogue_logging::Failure;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+impl QBittorrentClient {
+    /// Get the application preferences
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-application-preferences>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-application-preferences>
+    pub async fn get_preferences(&self) -> Result<Response<Preferences>, Failure<ClientAction>> {
+        let method = Method::GET;
+        let endpoint = "/app/preferences";
+        let response = self
+            .request_with_login(method.clone(), endpoint, &())
+            .await?;
+        deserialize_response::<Preferences>(&method, endpoint, response).await
+    }
+
+    /// Set application preferences
+    ///
+    /// Only the fields in `patch` are sent. Other preferences are unchanged.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#set-application-preferences>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#set-application-preferences>
+    pub async fn set_preferences(
+        &self,
+        patch: PreferencesPatch,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        let data = vec![("json", patch.to_json())];
+        let response = self
+            .request_with_login(Method::POST, "/app/setPreferences", &data)
+            .await?;
+        Ok(success_response(&response))
+    }
+}
+
+/// Application preferences.
+///
+/// Fields present in all versions from v4.1 onwards are required. Fields added or removed
+/// in later versions are `Option`. All other keys are kept in [`Preferences::other`].
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-application-preferences>
+/// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-application-preferences>
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/webui/api/appcontroller.cpp>
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "mirrors the qBittorrent API response"
+)]
+pub struct Preferences {
+    // Behavior
+    /// User interface language.
+    pub locale: String,
+
+    // Downloads
+    /// Default save path for torrents.
+    pub save_path: String,
+
+    /// Whether incomplete torrents are saved to `temp_path`.
+    pub temp_path_enabled: bool,
+
+    /// Path for incomplete torrents.
+    pub temp_path: String,
+
+    /// Whether disk space is preallocated for all files.
+    pub preallocate_all: bool,
+
+    /// Whether `.!qB` is appended to incomplete files.
+    pub incomplete_files_ext: bool,
+
+    /// Whether Automatic Torrent Management is enabled by default.
+    pub auto_tmm_enabled: bool,
+
+    /// Whether to relocate torrents when their category changes.
+    pub torrent_changed_tmm_enabled: bool,
+
+    /// Whether to relocate affected torrents when the default save path changes.
+    pub save_path_changed_tmm_enabled: bool,
+
+    /// Whether to relocate affected torrents when their category save path changes.
+    pub category_changed_tmm_enabled: bool,
+
+    /// Directory to copy .torrent files to. Empty if disabled.
+    pub export_dir: String,
+
+    /// Directory to copy .torrent files of completed downloads to. Empty if disabled.
+    pub export_dir_fin: String,
+
+    /// Whether new torrents are added stopped.
+    ///
+    /// Added in v5.0. Replaces `start_paused_enabled`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add_stopped_enabled: Option<bool>,
+
+    /// Whether new torrents are added paused.
+    ///
+    /// Replaced by `add_stopped_enabled` in v5.0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_paused_enabled: Option<bool>,
+
+    /// Content layout of new torrents: `Original`, `Subfolder` or `NoSubfolder`.
+    ///
+    /// Added in v4.3.2. Replaces `create_subfolder_enabled`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub torrent_content_layout: Option<String>,
+
+    /// Whether a subfolder is created for multi-file torrents.
+    ///
+    /// Replaced by `torrent_content_layout` in v4.3.2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_subfolder_enabled: Option<bool>,
+
+    /// Whether to run `autorun_program` when a torrent finishes.
+    pub autorun_enabled: bool,
+
+    /// Program to run when a torrent finishes.
+    pub autorun_program: String,
+
+    // Queueing
+    /// Whether torrent queueing is enabled.
+    pub queueing_enabled: bool,
+
+    /// Maximum number of active downloads.
+    pub max_active_downloads: i64,
+
+    /// Maximum number of active torrents.
+    pub max_active_torrents: i64,
+
+    /// Maximum number of active uploads.
+    pub max_active_uploads: i64,
+
+    /// Whether slow torrents are excluded from the active limits.
+    pub dont_count_slow_torrents: bool,
+
+    /// Download rate threshold below which a torrent is slow (KiB/s).
+    pub slow_torrent_dl_rate_threshold: i64,
+
+    /// Upload rate threshold below which a torrent is slow (KiB/s).
+    pub slow_torrent_ul_rate_threshold: i64,
+
+    /// Time a torrent must be below the thresholds before it is slow (seconds).
+    pub slow_torrent_inactive_timer: i64,
+
+    // Share limits
+    /// Whether the global share ratio limit is enabled.
+    pub max_ratio_enabled: bool,
+
+    /// Global share ratio limit.
+    pub max_ratio: f64,
+
+    /// Action when a share limit is reached.
+    /// - `0` pause or stop
+    /// - `1` remove
+    /// - `2` remove with content
+    /// - `3` enable super seeding
+    pub max_ratio_act: i64,
+
+    /// Whether the global seeding time limit is enabled.
+    ///
+    /// Not returned by all versions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_seeding_time_enabled: Option<bool>,
+
+    /// Global seeding time limit (minutes).
+    ///
+    /// Not returned by all versions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_seeding_time: Option<i64>,
+
+    /// Whether the global inactive seeding time limit is enabled.
+    ///
+    /// Added in v4.6
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_inactive_seeding_time_enabled: Option<bool>,
+
+    /// Global inactive seeding time limit (minutes).
+    ///
+    /// Added in v4.6
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_inactive_seeding_time: Option<i64>,
+
+    // Connection
+    /// Port for incoming connections.
+    pub listen_port: i64,
+
+    /// Whether UPnP/NAT-PMP port forwarding is enabled.
+    pub upnp: bool,
+
+    /// Whether a random port is used on each start.
+    pub random_port: bool,
+
+    /// Global maximum number of connections.
+    pub max_connec: i64,
+
+    /// Maximum number of connections per torrent.
+    pub max_connec_per_torrent: i64,
+
+    /// Global maximum number of upload slots.
+    pub max_uploads: i64,
+
+    /// Maximum number of upload slots per torrent.
+    pub max_uploads_per_torrent: i64,
+
+    // Speed
+    /// Global download speed limit (bytes/s). `0` if there is no limit.
+    pub dl_limit: i64,
+
+    /// Global upload speed limit (bytes/s). `0` if there is no limit.
+    pub up_limit: i64,
+
+    /// Alternative download speed limit (bytes/s). `0` if there is no limit.
+    pub alt_dl_limit: i64,
+
+    /// Alternative upload speed limit (bytes/s). `0` if there is no limit.
+    pub alt_up_limit: i64,
+
+    /// Whether speed limits apply to uTP connections.
+    pub limit_utp_rate: bool,
+
+    /// Whether speed limits include transport overhead.
+    pub limit_tcp_overhead: bool,
+
+    /// Whether speed limits apply to peers on the local network.
+    pub limit_lan_peers: bool,
+
+    /// Whether the alternative speed limits are scheduled.
+    pub scheduler_enabled: bool,
+
+    /// Hour the alternative speed limits start.
+    pub schedule_from_hour: i64,
+
+    /// Minute the alternative speed limits start.
+    pub schedule_from_min: i64,
+
+    /// Hour the alternative speed limits end.
+    pub schedule_to_hour: i64,
+
+    /// Minute the alternative speed limits end.
+    pub schedule_to_min: i64,
+
+    /// Days the schedule applies to.
+    /// - `0` every day
+    /// - `1` weekdays
+    /// - `2` weekends
+    /// - `3` to `9` Monday to Sunday
+    pub scheduler_days: i64,
+
+    // BitTorrent
+    /// Whether DHT is enabled.
+    pub dht: bool,
+
+    /// Whether peer exchange is enabled.
+    pub pex: bool,
+
+    /// Whether local peer discovery is enabled.
+    pub lsd: bool,
+
+    /// Encryption mode.
+    /// - `0` prefer encryption
+    /// - `1` require encryption
+    /// - `2` disable encryption
+    pub encryption: i64,
+
+    /// Whether anonymous mode is enabled.
+    pub anonymous_mode: bool,
+
+    // IP filtering
+    /// Whether the IP filter is enabled.
+    pub ip_filter_enabled: bool,
+
+    /// Path to the IP filter file.
+    pub ip_filter_path: String,
+
+    /// Whether the IP filter also applies to trackers.
+    pub ip_filter_trackers: bool,
+
+    // Web UI
+    /// Address the Web UI listens on.
+    pub web_ui_address: String,
+
+    /// Port the Web UI listens on.
+    pub web_ui_port: i64,
+
+    /// Whether UPnP/NAT-PMP is used for the Web UI port.
+    pub web_ui_upnp: bool,
+
+    /// Web UI username.
+    pub web_ui_username: String,
+
+    /// Whether CSRF protection is enabled.
+    pub web_ui_csrf_protection_enabled: bool,
+
+    /// Whether clickjacking protection is enabled.
+    pub web_ui_clickjacking_protection_enabled: bool,
+
+    /// Whether clients on localhost skip authentication.
+    pub bypass_local_auth: bool,
+
+    /// Whether clients in `bypass_auth_subnet_whitelist` skip authentication.
+    pub bypass_auth_subnet_whitelist_enabled: bool,
+
+    /// Subnets that skip authentication, one per line.
+    pub bypass_auth_subnet_whitelist: String,
+
+    /// Whether the Web UI uses HTTPS.
+    pub use_https: bool,
+
+    // RSS
+    /// Interval between RSS feed refreshes (minutes).
+    pub rss_refresh_interval: i64,
+
+    /// Maximum number of articles stored per feed.
+    pub rss_max_articles_per_feed: i64,
+
+    /// Whether RSS feeds are refreshed.
+    pub rss_processing_enabled: bool,
+
+    /// Whether RSS auto downloading is enabled.
+    pub rss_auto_downloading_enabled: bool,
+
+    /// Preferences not typed by this library, keyed by their API name.
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+#[cfg(feature = "mock")]
+impl Preferences {
+    /// Create a mock `Preferences` for testing
+    #[must_use]
+    pub fn mock() -> Self {
+        serde_json::from_str(include_str!("tests/fixtures/app_preferences_v5.json"))
+            .expect("v5 fixture should deserialize")
+    }
+}
+
+/// Changes to apply with
+/// [`QBittorrentClient::set_preferences`](crate::QBittorrentClient::set_preferences).
+///
+/// Keys are API preference names such as `dl_limit`.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct PreferencesPatch(Map<String, Value>);
+
+impl PreferencesPatch {
+    /// Create an empty patch.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a patch of the preferences in `desired` that differ from `current`.
+    ///
+    /// Typed fields that are `None` in `desired` are left unchanged.
+    #[must_use]
+    pub fn diff(current: &Preferences, desired: &Preferences) -> Self {
+        let current = to_map(current);
+        Self(
+            to_map(desired)
+                .into_iter()
+                .filter(|(key, value)| current.get(key) != Some(value))
+                .collect(),
+        )
+    }
+
+    /// Set a preference by its API name.
+    #[must_use]
+    pub fn with(mut self, key: &str, value: impl Into<Value>) -> Self {
+        self.0.insert(key.to_owned(), value.into());
+        self
+    }
+
+    /// Whether the patch has no changes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Changed preferences keyed by API name.
+    #[must_use]
+    pub fn changes(&self) -> &Map<String, Value> {
+        &self.0
+    }
+
+    /// Serialize as the `json` form parameter expected by the API.
+    fn to_json(&self) -> String {
+        Value::Object(self.0.clone()).to_string()
+    }
+}
+
+/// Serialize preferences to a map keyed by API name.
+pub(crate) fn to_map(preferences: &Preferences) -> Map<String, Value> {
+    match serde_json::to_value(preferences) {
+        Ok(Value::Object(map)) => map,
+        _ => Map::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QBittorrentClientOptions;
+    use crate::tests::init_logger;
+    use insta::assert_yaml_snapshot;
+    use log::trace;
+    use rogue_config::{OptionsProvider, YamlOptionsProvider};
+    use std::collections::BTreeMap;
+    use std::error::Error;
+
+    const FIXTURE_V4: &str = include_str!("tests/fixtures/app_preferences_v4.json");
+    const FIXTURE_V5: &str = include_str!("tests/fixtures/app_preferences_v5.json");
+
+    fn fixture_v5() -> Preferences {
+        serde_json::from_str(FIXTURE_V5).expect("v5 fixture should deserialize")
+    }
+
+    #[tokio::test]
+    #[ignore = "integration test requiring API credentials"]
+    async fn get_preferences() -> Result<(), Box<dyn Error>> {
+        // Arrange
+        init_logger();
+        let options: QBittorrentClientOptions =
+            YamlOptionsProvider::get().map_err(|e| e.to_string())?;
+        let client = QBittorrentClient::from_options(options);
+
+        // Act
+        let response = client.get_preferences().await?;
+        trace!("{}", response.to_json_pretty());
+
+        // Assert
+        let result = response.get_result("get_preferences")?;
+        assert!(!result.save_path.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_v4_fixture() {
+        let preferences: Preferences =
+            serde_json::from_str(FIXTURE_V4).expect("v4 fixture should deserialize");
+        assert_eq!(preferences.start_paused_enabled, Some(false));
+        assert_eq!(preferences.add_stopped_enabled, None);
+        let other: BTreeMap<_, _> = preferences.other.iter().collect();
+        assert_yaml_snapshot!(other);
+    }
+
+    #[test]
+    fn deserialize_v5_fixture() {
+        let preferences = fixture_v5();
+        assert_eq!(preferences.add_stopped_enabled, Some(false));
+        assert_eq!(preferences.start_paused_enabled, None);
+        let other: BTreeMap<_, _> = preferences.other.iter().collect();
+        assert_yaml_snapshot!(other);
+    }
+
+    #[test]
+    fn serialize_round_trip() {
+        let json: Value = serde_json::from_str(FIXTURE_V5).expect("fixture should be JSON");
+        let preferences = fixture_v5();
+        let serialized = serde_json::to_value(&preferences).expect("should serialize");
+        assert_eq!(serialized, json);
+    }
+
+    #[test]
+    fn diff_only_changed() {
+        // Arrange
+        let current = fixture_v5();
+        let mut desired = current.clone();
+        desired.dl_limit = 1_048_576;
+        desired.add_stopped_enabled = None;
+        desired
+            .other
+            .insert("announce_to_all_tiers".to_owned(), Value::Bool(false));
+
+        // Act
+        let patch = PreferencesPatch::diff(&current, &desired);
+
+        // Assert
+        let expected = PreferencesPatch::new()
+            .with("dl_limit", 1_048_576)
+            .with("announce_to_all_tiers", false);
+        assert_eq!(patch, expected);
+    }
+
+    #[test]
+    fn diff_unchanged_is_empty() {
+        let current = fixture_v5();
+        assert!(PreferencesPatch::diff(&current, &current.clone()).is_empty());
+    }
+
+    #[test]
+    fn patch_to_json() {
+        let patch = PreferencesPatch::new()
+            .with("dl_limit", 0)
+            .with("save_path", "/downloads");
+        assert_eq!(
+            patch.to_json(),
+            r#"{"dl_limit":0,"save_path":"/downloads"}"#
+        );
+    }
+}
@@ -0,0 +1,314 @@
+//! Torrent queue priority.
+
+use std::collections::HashMap;
+
+use crate::client::{join_hashes, success_response};
+use crate::get_torrents::{FilterOptions, Torrent};
+use crate::{QBittorrentClient, Response};
+use colored::Colorize;
+use log::debug;
+use reqwest::Method;
+use rogue_logging::Failure;
+use thiserror::Error;
+
+impl QBittorrentClient {
+    /// Move torrents one position up the queue
+    ///
+    /// Returns [`QueueAction::QueueingDisabled`] if torrent queueing is disabled.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#increase-torrent-priority>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#increase-torrent-priority>
+    pub async fn increase_priority(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<QueueAction>> {
+        self.send_priority("/torrents/increasePrio", &hashes).await
+    }
+
+    /// Move torrents one position down the queue
+    ///
+    /// Returns [`QueueAction::QueueingDisabled`] if torrent queueing is disabled.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#decrease-torrent-priority>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#decrease-torrent-priority>
+    pub async fn decrease_priority(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<QueueAction>> {
+        self.send_priority("/torrents/decreasePrio", &hashes).await
+    }
+
+    /// Move torrents to the top of the queue
+    ///
+    /// Returns [`QueueAction::QueueingDisabled`] if torrent queueing is disabled.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#maximal-torrent-priority>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#maximal-torrent-priority>
+    pub async fn top_priority(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<QueueAction>> {
+        self.send_priority("/torrents/topPrio", &hashes).await
+    }
+
+    /// Move torrents to the bottom of the queue
+    ///
+    /// Returns [`QueueAction::QueueingDisabled`] if torrent queueing is disabled.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#minimal-torrent-priority>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#minimal-torrent-priority>
+    pub async fn bottom_priority(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<QueueAction>> {
+        self.send_priority("/torrents/bottomPrio", &hashes).await
+    }
+
+    /// Rewrite the queue so that `hashes` are at the top in the given order
+    ///
+    /// - The first hash is given the highest priority.
+    /// - Queued torrents not in `hashes` keep their relative order below them.
+    /// - Torrents that are already in order are not moved, so each call sends at most
+    ///   one `/torrents/topPrio` request per torrent that must move.
+    ///
+    /// Returns the hashes that were moved, in the order they were moved.
+    pub async fn reorder_queue(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<Vec<String>>, Failure<QueueAction>> {
+        let filters = FilterOptions {
+            hashes: Some(join_hashes(&hashes)),
+            ..FilterOptions::default()
+        };
+        let response = self
+            .get_torrents(filters)
+            .await
+            .map_err(Failure::wrap(QueueAction::Request))?;
+        let status_code = response.status_code;
+        let torrents = response
+            .get_result("get_torrents")
+            .map_err(Failure::wrap(QueueAction::Request))?;
+        self.apply_queue_order(&hashes, &torrents, status_code)
+            .await
+    }
+
+    /// Rewrite the whole queue in ascending order of `key`
+    ///
+    /// - Use a `key` such as `|torrent| torrent.size` or `|torrent| torrent.added_on`.
+    /// - Torrents with equal keys keep their current relative order.
+    /// - Torrents that are not queued are ignored.
+    pub async fn sort_queue<K, F>(
+        &self,
+        key: F,
+    ) -> Result<Response<Vec<String>>, Failure<QueueAction>>
+    where
+        K: Ord,
+        F: Fn(&Torrent) -> K,
+    {
+        let response = self
+            .get_torrents(FilterOptions::default())
+            .await
+            .map_err(Failure::wrap(QueueAction::Request))?;
+        let status_code = response.status_code;
+        let mut torrents = response
+            .get_result("get_torrents")
+            .map_err(Failure::wrap(QueueAction::Request))?;
+        torrents.retain(is_queued);
+        torrents.sort_by_key(|torrent| torrent.priority);
+        torrents.sort_by_key(&key);
+        let hashes: Vec<String> = torrents
+            .iter()
+            .map(|torrent| torrent.hash.clone())
+            .collect();
+        self.apply_queue_order(&hashes, &torrents, status_code)
+            .await
+    }
+
+    async fn apply_queue_order(
+        &self,
+        hashes: &[String],
+        torrents: &[Torrent],
+        status_code: Option<u16>,
+    ) -> Result<Response<Vec<String>>, Failure<QueueAction>> {
+        let moves = get_queue_moves(hashes, torrents);
+        debug!(
+            "{} {} of {} torrents to reorder the queue",
+            "Moving".bold(),
+            moves.len(),
+            hashes.len()
+        );
+        let mut status_code = status_code;
+        for hash in &moves {
+            let response = self.top_priority(vec![hash.clone()]).await?;
+            status_code = response.status_code;
+        }
+        Ok(Response {
+            status_code,
+            result: Some(moves),
+        })
+    }
+
+    async fn send_priority(
+        &self,
+        endpoint: &str,
+        hashes: &[String],
+    ) -> Result<Response<bool>, Failure<QueueAction>> {
+        let data = vec![("hashes", join_hashes(hashes))];
+        let response = self
+            .request_with_login(Method::POST, endpoint, &data)
+            .await
+            .map_err(Failure::wrap(QueueAction::Request))?;
+        if response.status().as_u16() == 409 {
+            return Err(Failure::from_action(QueueAction::QueueingDisabled)
+                .with("endpoint", endpoint)
+                .with("status_code", "409"));
+        }
+        Ok(success_response(&response))
+    }
+}
+
+/// Whether the torrent has a queue position.
+fn is_queued(torrent: &Torrent) -> bool {
+    torrent.priority > 0
+}
+
+/// Determine which torrents must be moved to the top of the queue, in call order,
+/// so that `desired` ends up at the top of the queue in order.
+///
+/// Moving a torrent to the top pushes every other torrent down one position, so
+/// `desired` is processed from last to first. Torrents that are not queued are ignored.
+fn get_queue_moves(desired: &[String], torrents: &[Torrent]) -> Vec<String> {
+    let positions: HashMap<&str, i64> = torrents
+        .iter()
+        .filter(|torrent| is_queued(torrent))
+        .map(|torrent| (torrent.hash.as_str(), torrent.priority))
+        .collect();
+    let desired: Vec<(&String, i64)> = desired
+        .iter()
+        .filter_map(|hash| {
+            positions
+                .get(hash.as_str())
+                .map(|position| (hash, *position))
+        })
+        .collect();
+    let keep = get_ordered_tail(&desired);
+    desired
+        .iter()
+        .take(desired.len() - keep)
+        .rev()
+        .map(|(hash, _)| (*hash).clone())
+        .collect()
+}
+
+/// Length of the tail of `desired` that will already be in place once the torrents
+/// before it have been moved to the top of the queue.
+///
+/// Queue positions are contiguous from `1`, so the tail is only in place if no other
+/// torrent is queued above its last torrent and its positions are ascending.
+fn get_ordered_tail(desired: &[(&String, i64)]) -> usize {
+    let Some((_, last)) = desired.last() else {
+        return 0;
+    };
+    let above = desired
+        .iter()
+        .filter(|(_, position)| position <= last)
+        .count();
+    if i64::try_from(above).ok() != Some(*last) {
+        return 0;
+    }
+    let mut keep = 0;
+    let mut next = i64::MAX;
+    for (_, position) in desired.iter().rev() {
+        if *position >= next {
+            break;
+        }
+        next = *position;
+        keep += 1;
+    }
+    keep
+}
+
+/// Errors returned by queue operations
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Error)]
+pub enum QueueAction {
+    #[error("send request")]
+    Request,
+    #[error("change queue position because torrent queueing is disabled")]
+    QueueingDisabled,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE_V5: &str = include_str!("tests/fixtures/torrents_info_v5.json");
+
+    /// Create queued torrents with the given hashes at positions `1..=n`.
+    fn queued(hashes: &[&str]) -> Vec<Torrent> {
+        let fixture: Vec<Torrent> =
+            serde_json::from_str(FIXTURE_V5).expect("v5 fixture should deserialize");
+        let template = fixture.first().expect("fixture should not be empty");
+        hashes
+            .iter()
+            .zip(1..)
+            .map(|(hash, priority)| Torrent {
+                hash: (*hash).to_owned(),
+                priority,
+                ..template.clone()
+            })
+            .collect()
+    }
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| (*value).to_owned()).collect()
+    }
+
+    #[test]
+    fn queue_moves_reverse_order() {
+        let torrents = queued(&["a", "b", "c"]);
+        let moves = get_queue_moves(&strings(&["c", "b", "a"]), &torrents);
+        assert_eq!(moves, strings(&["b", "c"]));
+    }
+
+    #[test]
+    fn queue_moves_already_ordered() {
+        let torrents = queued(&["a", "b", "c"]);
+        let moves = get_queue_moves(&strings(&["a", "b", "c"]), &torrents);
+        assert!(moves.is_empty());
+    }
+
+    #[test]
+    fn queue_moves_ordered_but_not_at_top() {
+        let torrents = queued(&["x", "a", "b"]);
+        let moves = get_queue_moves(&strings(&["a", "b"]), &torrents);
+        assert_eq!(moves, strings(&["b", "a"]));
+    }
+
+    #[test]
+    fn queue_moves_only_out_of_order_prefix() {
+        let torrents = queued(&["a", "b", "c", "d"]);
+        let moves = get_queue_moves(&strings(&["d", "a", "b", "c"]), &torrents);
+        assert_eq!(moves, strings(&["d"]));
+    }
+
+    #[test]
+    fn queue_moves_other_torrent_above_tail() {
+        let torrents = queued(&["a", "x", "b", "c"]);
+        let moves = get_queue_moves(&strings(&["c", "a", "b"]), &torrents);
+        assert_eq!(moves, strings(&["b", "a", "c"]));
+    }
+
+    #[test]
+    fn queue_moves_skip_unqueued() {
+        let mut torrents = queued(&["a", "b"]);
+        for torrent in &mut torrents {
+            torrent.priority = 0;
+        }
+        let moves = get_queue_moves(&strings(&["b", "a"]), &torrents);
+        assert!(moves.is_empty());
+    }
+}
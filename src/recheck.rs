@@ -0,0 +1,348 @@
+//! Torrent recheck and reannounce, and waiting for rechecks to complete.
+
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use crate::client::{ClientAction, join_hashes, success_response};
+use crate::get_torrents::{FilterOptions, State, Torrent};
+use crate::wait::{PollOutcome, WaitOptions};
+use crate::{QBittorrentClient, Response};
+use colored::Colorize;
+use log::debug;
+use reqwest::Method;
+use rogue_logging::Failure;
+use serde::{Deserialize, Serialize};
+
+/// Time after which a torrent that was not seen checking is assumed to have been checked.
+const CHECK_GRACE_PERIOD: Duration = Duration::from_secs(10);
+
+impl QBittorrentClient {
+    /// Recheck the data of torrents
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#recheck-torrents>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#recheck-torrents>
+    pub async fn recheck_torrents(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        let data = vec![("hashes", join_hashes(&hashes))];
+        let response = self
+            .request_with_login(Method::POST, "/torrents/recheck", &data)
+            .await?;
+        Ok(success_response(&response))
+    }
+
+    /// Reannounce torrents to their trackers
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#reannounce-torrents>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#reannounce-torrents>
+    pub async fn reannounce_torrents(
+        &self,
+        hashes: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        let data = vec![("hashes", join_hashes(&hashes))];
+        let response = self
+            .request_with_login(Method::POST, "/torrents/reannounce", &data)
+            .await?;
+        Ok(success_response(&response))
+    }
+
+    /// Recheck torrents and wait for each check to complete
+    ///
+    /// - Torrents are rechecked in batches of [`WaitOptions::batch_size`] so that only a
+    ///   limited number of checks run concurrently on the server.
+    /// - A check has completed once the torrent has been seen in a checking state, or its
+    ///   progress has dropped below the progress before the recheck, and it is no longer
+    ///   checking.
+    /// - Torrents are polled immediately after the recheck is requested. A check that
+    ///   finishes before it is seen, as is typical for small torrents, is assumed to have
+    ///   completed once the torrent has not been seen checking for ten seconds.
+    /// - Results are returned in the order of `hashes`.
+    pub async fn recheck_and_wait(
+        &self,
+        hashes: Vec<String>,
+        options: WaitOptions,
+    ) -> Result<Vec<RecheckResult>, Failure<ClientAction>> {
+        let mut results = Vec::with_capacity(hashes.len());
+        for batch in hashes.chunks(options.batch_size(hashes.len())) {
+            debug!("{} {} torrents", "Rechecking".bold(), batch.len());
+            let filters = FilterOptions {
+                hashes: Some(join_hashes(batch)),
+                ..FilterOptions::default()
+            };
+            let torrents = self
+                .get_torrents(filters)
+                .await?
+                .get_result("get_torrents")?;
+            let mut tracker = RecheckTracker::new(&torrents, CHECK_GRACE_PERIOD);
+            self.recheck_torrents(batch.to_vec())
+                .await?
+                .get_result("recheck_torrents")?;
+            let outcomes = self
+                .poll_torrents(batch, &options, |torrent| tracker.get_status(torrent))
+                .await?;
+            results.extend(outcomes.into_iter().map(|(hash, outcome)| {
+                let status = match outcome {
+                    PollOutcome::Complete(status) => status,
+                    PollOutcome::NotFound => RecheckStatus::NotFound,
+                    PollOutcome::TimedOut(torrent) => RecheckStatus::TimedOut {
+                        progress: torrent.progress,
+                        state: torrent.state,
+                    },
+                };
+                RecheckResult { hash, status }
+            }));
+        }
+        Ok(results)
+    }
+}
+
+/// Track which checks have started so a torrent is only reported as checked after its
+/// check was seen, or after a grace period if the check was too fast to be seen.
+struct RecheckTracker {
+    /// Progress of each torrent before the recheck was requested.
+    initial_progress: HashMap<String, f64>,
+    /// Hashes of torrents seen in a checking state.
+    started: HashSet<String>,
+    /// When the recheck was requested.
+    requested: Instant,
+    /// Time after which a check that was not seen is assumed to have completed.
+    grace_period: Duration,
+}
+
+impl RecheckTracker {
+    /// Create a tracker from the torrents before the recheck was requested.
+    fn new(torrents: &[Torrent], grace_period: Duration) -> Self {
+        Self {
+            initial_progress: torrents
+                .iter()
+                .map(|torrent| (torrent.hash.clone(), torrent.progress))
+                .collect(),
+            started: HashSet::new(),
+            requested: Instant::now(),
+            grace_period,
+        }
+    }
+
+    /// Determine whether a check has finished, returning `None` while it is still in
+    /// progress or has not yet started.
+    fn get_status(&mut self, torrent: &Torrent) -> Option<RecheckStatus> {
+        if is_checking(&torrent.state) {
+            self.started.insert(torrent.hash.clone());
+            return None;
+        }
+        let is_reset = self
+            .initial_progress
+            .get(&torrent.hash)
+            .is_some_and(|initial| torrent.progress < *initial);
+        let is_started = is_reset || self.started.contains(&torrent.hash);
+        if !is_started && self.requested.elapsed() < self.grace_period {
+            return None;
+        }
+        Some(RecheckStatus::Checked {
+            progress: torrent.progress,
+            state: torrent.state.clone(),
+        })
+    }
+}
+
+/// Whether the torrent is queued for, or in the middle of, a data check.
+fn is_checking(state: &State) -> bool {
+    matches!(
+        state,
+        State::CheckingUP
+            | State::CheckingDL
+            | State::CheckingResumeData
+            | State::QueuedForChecking
+    )
+}
+
+/// Outcome of rechecking a single torrent with
+/// [`QBittorrentClient::recheck_and_wait`](crate::QBittorrentClient::recheck_and_wait).
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RecheckResult {
+    /// Torrent hash.
+    pub hash: String,
+    /// Outcome of the recheck.
+    pub status: RecheckStatus,
+}
+
+impl RecheckResult {
+    /// Whether the check completed and verified all of the selected data.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        matches!(self.status, RecheckStatus::Checked { progress, .. } if progress >= 1.0)
+    }
+}
+
+/// Outcome of a torrent recheck.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub enum RecheckStatus {
+    /// Check finished.
+    Checked {
+        /// Verified progress (percentage/100).
+        progress: f64,
+        /// State after checking.
+        state: State,
+    },
+    /// Torrent was not found on the server.
+    NotFound,
+    /// Check did not finish before [`WaitOptions::timeout`].
+    TimedOut {
+        /// Progress at the last poll (percentage/100).
+        progress: f64,
+        /// State at the last poll.
+        state: State,
+    },
+}
+
+#[cfg(test)]
+#[expect(clippy::indexing_slicing, reason = "indexing fixture torrents")]
+mod tests {
+    use super::*;
+    use std::slice::from_ref;
+
+    const FIXTURE_V5: &str = include_str!("tests/fixtures/torrents_info_v5.json");
+
+    fn fixture_torrent() -> Torrent {
+        let torrents: Vec<Torrent> =
+            serde_json::from_str(FIXTURE_V5).expect("v5 fixture should deserialize");
+        torrents[0].clone()
+    }
+
+    /// Get a tracker that has seen the check of `torrent` start.
+    fn started_tracker(torrent: &Torrent) -> RecheckTracker {
+        let mut tracker = RecheckTracker::new(from_ref(torrent), CHECK_GRACE_PERIOD);
+        let mut checking = torrent.clone();
+        checking.state = State::CheckingUP;
+        assert_eq!(tracker.get_status(&checking), None);
+        tracker
+    }
+
+    #[test]
+    fn recheck_status_checking() {
+        let mut torrent = fixture_torrent();
+        let mut tracker = RecheckTracker::new(from_ref(&torrent), CHECK_GRACE_PERIOD);
+        for state in [
+            State::CheckingUP,
+            State::CheckingDL,
+            State::CheckingResumeData,
+            State::QueuedForChecking,
+        ] {
+            torrent.state = state;
+            assert_eq!(tracker.get_status(&torrent), None);
+        }
+    }
+
+    #[test]
+    fn recheck_status_not_yet_started() {
+        let mut torrent = fixture_torrent();
+        torrent.state = State::StoppedUP;
+        torrent.progress = 1.0;
+        let mut tracker = RecheckTracker::new(from_ref(&torrent), CHECK_GRACE_PERIOD);
+        assert_eq!(tracker.get_status(&torrent), None);
+        torrent.state = State::CheckingUP;
+        torrent.progress = 0.0;
+        assert_eq!(tracker.get_status(&torrent), None);
+        torrent.state = State::StoppedUP;
+        torrent.progress = 1.0;
+        assert_eq!(
+            tracker.get_status(&torrent),
+            Some(RecheckStatus::Checked {
+                progress: 1.0,
+                state: State::StoppedUP,
+            })
+        );
+    }
+
+    #[test]
+    fn recheck_status_not_seen_after_grace_period() {
+        let mut torrent = fixture_torrent();
+        torrent.state = State::StoppedUP;
+        torrent.progress = 1.0;
+        let mut tracker = RecheckTracker::new(from_ref(&torrent), Duration::ZERO);
+        let result = RecheckResult {
+            hash: torrent.hash.clone(),
+            status: tracker
+                .get_status(&torrent)
+                .expect("check should be assumed finished"),
+        };
+        assert!(result.is_complete());
+    }
+
+    #[test]
+    fn recheck_status_checking_after_grace_period() {
+        let mut torrent = fixture_torrent();
+        torrent.state = State::QueuedForChecking;
+        let mut tracker = RecheckTracker::new(from_ref(&torrent), Duration::ZERO);
+        assert_eq!(tracker.get_status(&torrent), None);
+    }
+
+    #[test]
+    fn recheck_status_progress_reset() {
+        let mut torrent = fixture_torrent();
+        torrent.state = State::StoppedUP;
+        torrent.progress = 1.0;
+        let mut tracker = RecheckTracker::new(from_ref(&torrent), CHECK_GRACE_PERIOD);
+        torrent.state = State::StoppedDL;
+        torrent.progress = 0.5;
+        assert_eq!(
+            tracker.get_status(&torrent),
+            Some(RecheckStatus::Checked {
+                progress: 0.5,
+                state: State::StoppedDL,
+            })
+        );
+    }
+
+    #[test]
+    fn recheck_status_complete() {
+        let mut torrent = fixture_torrent();
+        let mut tracker = started_tracker(&torrent);
+        torrent.state = State::StoppedUP;
+        torrent.progress = 1.0;
+        let result = RecheckResult {
+            hash: torrent.hash.clone(),
+            status: tracker
+                .get_status(&torrent)
+                .expect("check should be finished"),
+        };
+        assert!(result.is_complete());
+    }
+
+    #[test]
+    fn recheck_status_incomplete() {
+        let mut torrent = fixture_torrent();
+        let mut tracker = started_tracker(&torrent);
+        torrent.state = State::StoppedDL;
+        torrent.progress = 0.999;
+        let result = RecheckResult {
+            hash: torrent.hash.clone(),
+            status: tracker
+                .get_status(&torrent)
+                .expect("check should be finished"),
+        };
+        assert!(!result.is_complete());
+        assert_eq!(
+            result.status,
+            RecheckStatus::Checked {
+                progress: 0.999,
+                state: State::StoppedDL,
+            }
+        );
+    }
+
+    #[test]
+    fn recheck_result_timed_out_is_not_complete() {
+        let result = RecheckResult {
+            hash: String::new(),
+            status: RecheckStatus::TimedOut {
+                progress: 1.0,
+                state: State::CheckingUP,
+            },
+        };
+        assert!(!result.is_complete());
+    }
+}
@@ -0,0 +1,554 @@
+//! Reconcile preferences, categories and tags with a desired state.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::QBittorrentClient;
+use crate::categories::{Category, CategoryDownloadPath};
+use crate::preferences::{Preferences, PreferencesPatch, to_map};
+use colored::Colorize;
+use log::debug;
+use rogue_logging::Failure;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+impl QBittorrentClient {
+    /// Compare the desired state to the live instance
+    ///
+    /// Returns the changes required for the live instance to match `desired`.
+    /// Nothing is changed on the server.
+    pub async fn plan(&self, desired: &DesiredState) -> Result<Plan, Failure<ReconcileAction>> {
+        let preferences = self
+            .get_preferences()
+            .await
+            .and_then(|response| response.get_result("get_preferences"))
+            .map_err(Failure::wrap(ReconcileAction::GetLiveState))?;
+        let categories = self
+            .get_categories()
+            .await
+            .and_then(|response| response.get_result("get_categories"))
+            .map_err(Failure::wrap(ReconcileAction::GetLiveState))?;
+        let tags = self
+            .get_tags()
+            .await
+            .and_then(|response| response.get_result("get_tags"))
+            .map_err(Failure::wrap(ReconcileAction::GetLiveState))?;
+        Ok(get_plan(desired, &preferences, &categories, &tags))
+    }
+
+    /// Apply the changes of a plan to the live instance
+    ///
+    /// Preferences are set in a single request, followed by categories and then tags.
+    pub async fn apply_plan(&self, plan: &Plan) -> Result<(), Failure<ReconcileAction>> {
+        let mut preferences = PreferencesPatch::new();
+        let mut remove_categories = Vec::new();
+        let mut create_tags = Vec::new();
+        let mut delete_tags = Vec::new();
+        for change in &plan.changes {
+            match change {
+                Change::SetPreference { key, desired, .. } => {
+                    preferences = preferences.with(key, desired.clone());
+                }
+                Change::RemoveCategory(name) => remove_categories.push(name.clone()),
+                Change::CreateTag(tag) => create_tags.push(tag.clone()),
+                Change::DeleteTag(tag) => delete_tags.push(tag.clone()),
+                Change::CreateCategory(_) | Change::EditCategory { .. } => {}
+            }
+        }
+        if !preferences.is_empty() {
+            debug!(
+                "{} {} preferences",
+                "Setting".bold(),
+                preferences.changes().len()
+            );
+            self.set_preferences(preferences)
+                .await
+                .and_then(|response| response.get_result("set_preferences"))
+                .map_err(Failure::wrap(ReconcileAction::ApplyPreferences))?;
+        }
+        for change in &plan.changes {
+            let response = match change {
+                Change::CreateCategory(category) => {
+                    debug!("{} category {}", "Creating".bold(), category.name);
+                    self.create_category(category.clone()).await
+                }
+                Change::EditCategory { desired, .. } => {
+                    debug!("{} category {}", "Editing".bold(), desired.name);
+                    self.edit_category(desired.clone()).await
+                }
+                _ => continue,
+            };
+            response
+                .map_err(Failure::wrap(ReconcileAction::ApplyCategories))?
+                .get_result("apply_category")
+                .map_err(Failure::wrap(ReconcileAction::ApplyCategories))?;
+        }
+        if !remove_categories.is_empty() {
+            debug!(
+                "{} {} categories",
+                "Removing".bold(),
+                remove_categories.len()
+            );
+            self.remove_categories(remove_categories)
+                .await
+                .and_then(|response| response.get_result("remove_categories"))
+                .map_err(Failure::wrap(ReconcileAction::ApplyCategories))?;
+        }
+        if !create_tags.is_empty() {
+            debug!("{} {} tags", "Creating".bold(), create_tags.len());
+            self.create_tags(create_tags)
+                .await
+                .and_then(|response| response.get_result("create_tags"))
+                .map_err(Failure::wrap(ReconcileAction::ApplyTags))?;
+        }
+        if !delete_tags.is_empty() {
+            debug!("{} {} tags", "Deleting".bold(), delete_tags.len());
+            self.delete_tags(delete_tags)
+                .await
+                .and_then(|response| response.get_result("delete_tags"))
+                .map_err(Failure::wrap(ReconcileAction::ApplyTags))?;
+        }
+        Ok(())
+    }
+}
+
+/// Desired preferences, categories and tags of a qBittorrent instance.
+///
+/// Deserialize it from a document in any serde format, such as YAML, with top-level
+/// `preferences`, `categories`, `tags` and `prune` keys.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct DesiredState {
+    /// Preferences keyed by API name.
+    ///
+    /// Preferences that are not listed are left unchanged.
+    #[serde(default)]
+    pub preferences: PreferencesPatch,
+
+    /// Categories keyed by name.
+    #[serde(default)]
+    pub categories: BTreeMap<String, DesiredCategory>,
+
+    /// Tags.
+    #[serde(default)]
+    pub tags: BTreeSet<String>,
+
+    /// Remove categories and tags that are not listed.
+    #[serde(default)]
+    pub prune: bool,
+}
+
+/// Desired options of a category.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DesiredCategory {
+    /// Path where torrents in this category are saved.
+    ///
+    /// Empty to use the default save path.
+    #[serde(default)]
+    pub save_path: String,
+
+    /// Path where incomplete torrents in this category are downloaded.
+    ///
+    /// `None` leaves the download path of an existing category unchanged.
+    ///
+    /// Added in v4.5
+    #[serde(default)]
+    pub download_path: Option<String>,
+}
+
+impl DesiredCategory {
+    fn to_category(&self, name: &str) -> Category {
+        Category {
+            name: name.to_owned(),
+            save_path: self.save_path.clone(),
+            download_path: self
+                .download_path
+                .clone()
+                .map_or(CategoryDownloadPath::Unset, CategoryDownloadPath::Path),
+        }
+    }
+}
+
+/// Changes required for a live instance to match a [`DesiredState`].
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Plan {
+    /// Changes in the order they are applied.
+    pub changes: Vec<Change>,
+}
+
+impl Plan {
+    /// Whether the live instance already matches the desired state.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl Display for Plan {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        if self.changes.is_empty() {
+            return writeln!(formatter, "No changes");
+        }
+        for change in &self.changes {
+            writeln!(formatter, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A single change in a [`Plan`].
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum Change {
+    /// Set a preference.
+    SetPreference {
+        /// API name of the preference.
+        key: String,
+        /// Live value. `None` if the server did not return the preference.
+        current: Option<Value>,
+        /// Desired value.
+        desired: Value,
+    },
+    /// Create a category.
+    CreateCategory(Category),
+    /// Edit the paths of a category.
+    EditCategory {
+        /// Live category.
+        current: Category,
+        /// Desired category.
+        desired: Category,
+    },
+    /// Remove a category.
+    RemoveCategory(String),
+    /// Create a tag.
+    CreateTag(String),
+    /// Delete a tag.
+    DeleteTag(String),
+}
+
+impl Display for Change {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Change::SetPreference {
+                key,
+                current,
+                desired,
+            } => {
+                let current = current
+                    .as_ref()
+                    .map_or_else(|| "(not set)".to_owned(), ToString::to_string);
+                write!(formatter, "~ preference {key}: {current} -> {desired}")
+            }
+            Change::CreateCategory(category) => {
+                write!(
+                    formatter,
+                    "+ category {}: {}",
+                    category.name,
+                    format_category(category)
+                )
+            }
+            Change::EditCategory { current, desired } => write!(
+                formatter,
+                "~ category {}: {} -> {}",
+                desired.name,
+                format_category(current),
+                format_category(desired)
+            ),
+            Change::RemoveCategory(name) => write!(formatter, "- category {name}"),
+            Change::CreateTag(tag) => write!(formatter, "+ tag {tag}"),
+            Change::DeleteTag(tag) => write!(formatter, "- tag {tag}"),
+        }
+    }
+}
+
+fn format_category(category: &Category) -> String {
+    let save_path = if category.save_path.is_empty() {
+        "(default)"
+    } else {
+        &category.save_path
+    };
+    match &category.download_path {
+        CategoryDownloadPath::Unset => format!("save path {save_path}"),
+        CategoryDownloadPath::Disabled => format!("save path {save_path}, download path disabled"),
+        CategoryDownloadPath::Path(download_path) => {
+            format!("save path {save_path}, download path {download_path}")
+        }
+    }
+}
+
+/// Determine the changes required for the live state to match `desired`.
+fn get_plan(
+    desired: &DesiredState,
+    preferences: &Preferences,
+    categories: &HashMap<String, Category>,
+    tags: &[String],
+) -> Plan {
+    let mut changes = Vec::new();
+    let live = to_map(preferences);
+    for (key, value) in desired.preferences.changes() {
+        let current = live.get(key);
+        if current.is_some_and(|current| is_same_value(current, value)) {
+            continue;
+        }
+        changes.push(Change::SetPreference {
+            key: key.clone(),
+            current: current.cloned(),
+            desired: value.clone(),
+        });
+    }
+    for (name, category) in &desired.categories {
+        let mut category = category.to_category(name);
+        match categories.get(name) {
+            None => changes.push(Change::CreateCategory(category)),
+            Some(current) => {
+                // An edit replaces every option so keep the live download path if not desired.
+                if category.download_path.is_unset() {
+                    category.download_path = current.download_path.clone();
+                }
+                if *current != category {
+                    changes.push(Change::EditCategory {
+                        current: current.clone(),
+                        desired: category,
+                    });
+                }
+            }
+        }
+    }
+    if desired.prune {
+        let mut removed: Vec<&String> = categories
+            .keys()
+            .filter(|name| !desired.categories.contains_key(*name))
+            .collect();
+        removed.sort();
+        changes.extend(
+            removed
+                .into_iter()
+                .map(|name| Change::RemoveCategory(name.clone())),
+        );
+    }
+    let live_tags: BTreeSet<&String> = tags.iter().collect();
+    changes.extend(
+        desired
+            .tags
+            .iter()
+            .filter(|tag| !live_tags.contains(tag))
+            .map(|tag| Change::CreateTag(tag.clone())),
+    );
+    if desired.prune {
+        changes.extend(
+            live_tags
+                .into_iter()
+                .filter(|tag| !desired.tags.contains(*tag))
+                .map(|tag| Change::DeleteTag(tag.clone())),
+        );
+    }
+    Plan { changes }
+}
+
+/// Compare preference values, treating numbers as equal if their values are equal
+/// so that `2` in YAML matches `2.0` from the API.
+fn is_same_value(current: &Value, desired: &Value) -> bool {
+    match (current, desired) {
+        (Value::Number(current), Value::Number(desired)) => current.as_f64() == desired.as_f64(),
+        _ => current == desired,
+    }
+}
+
+/// Errors returned by reconcile operations
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Error)]
+pub enum ReconcileAction {
+    #[error("get live state")]
+    GetLiveState,
+    #[error("apply preferences")]
+    ApplyPreferences,
+    #[error("apply categories")]
+    ApplyCategories,
+    #[error("apply tags")]
+    ApplyTags,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QBittorrentClientOptions;
+    use crate::tests::init_logger;
+    use insta::assert_snapshot;
+    use log::trace;
+    use rogue_config::{OptionsProvider, YamlOptionsProvider};
+    use std::error::Error;
+
+    const DESIRED_STATE: &str = include_str!("tests/fixtures/desired_state.yml");
+    const PREFERENCES_V5: &str = include_str!("tests/fixtures/app_preferences_v5.json");
+    const CATEGORIES_V5: &str = include_str!("tests/fixtures/torrents_categories_v5.json");
+
+    fn live_preferences() -> Preferences {
+        serde_json::from_str(PREFERENCES_V5).expect("preferences fixture should deserialize")
+    }
+
+    fn live_categories() -> HashMap<String, Category> {
+        serde_json::from_str(CATEGORIES_V5).expect("categories fixture should deserialize")
+    }
+
+    fn live_tags() -> Vec<String> {
+        vec!["new".to_owned(), "old".to_owned()]
+    }
+
+    fn desired_state() -> DesiredState {
+        serde_yaml::from_str(DESIRED_STATE).expect("desired state should deserialize")
+    }
+
+    #[tokio::test]
+    #[ignore = "integration test requiring API credentials"]
+    async fn plan_empty_desired_state() -> Result<(), Box<dyn Error>> {
+        // Arrange
+        init_logger();
+        let options: QBittorrentClientOptions =
+            YamlOptionsProvider::get().map_err(|e| e.to_string())?;
+        let client = QBittorrentClient::from_options(options);
+
+        // Act
+        let plan = client.plan(&DesiredState::default()).await?;
+        trace!("{plan}");
+
+        // Assert
+        assert!(plan.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn plan_from_fixture() {
+        let plan = get_plan(
+            &desired_state(),
+            &live_preferences(),
+            &live_categories(),
+            &live_tags(),
+        );
+        assert_snapshot!(plan.to_string());
+    }
+
+    #[test]
+    fn plan_without_prune_keeps_extra() {
+        let desired = DesiredState {
+            prune: false,
+            ..desired_state()
+        };
+        let plan = get_plan(
+            &desired,
+            &live_preferences(),
+            &live_categories(),
+            &live_tags(),
+        );
+        assert!(
+            !plan
+                .changes
+                .iter()
+                .any(|change| matches!(change, Change::RemoveCategory(_) | Change::DeleteTag(_)))
+        );
+    }
+
+    #[test]
+    fn plan_empty_when_matching() {
+        // Arrange
+        let categories = live_categories();
+        let desired = DesiredState {
+            preferences: PreferencesPatch::new()
+                .with("max_ratio", 1.5)
+                .with("max_active_downloads", 3.0),
+            categories: categories
+                .iter()
+                .map(|(name, category)| {
+                    let desired = DesiredCategory {
+                        save_path: category.save_path.clone(),
+                        download_path: category.download_path.path().map(ToOwned::to_owned),
+                    };
+                    (name.clone(), desired)
+                })
+                .collect(),
+            tags: live_tags().into_iter().collect(),
+            prune: true,
+        };
+
+        // Act
+        let plan = get_plan(&desired, &live_preferences(), &categories, &live_tags());
+
+        // Assert
+        assert!(plan.is_empty());
+        assert_eq!(plan.to_string(), "No changes\n");
+    }
+
+    #[test]
+    fn plan_edit_save_path_keeps_download_path() {
+        // Arrange
+        let desired = DesiredState {
+            categories: BTreeMap::from([(
+                "movies".to_owned(),
+                DesiredCategory {
+                    save_path: "/downloads/films".to_owned(),
+                    download_path: None,
+                },
+            )]),
+            ..DesiredState::default()
+        };
+
+        // Act
+        let plan = get_plan(&desired, &live_preferences(), &live_categories(), &[]);
+
+        // Assert
+        assert_eq!(plan.changes.len(), 1);
+        let desired = plan
+            .changes
+            .iter()
+            .find_map(|change| match change {
+                Change::EditCategory { desired, .. } => Some(desired),
+                _ => None,
+            })
+            .expect("plan should edit the category");
+        let form = desired.to_form();
+        assert!(form.contains(&("savePath", "/downloads/films".to_owned())));
+        assert!(!form.contains(&("downloadPathEnabled", "false".to_owned())));
+    }
+
+    #[test]
+    fn plan_edit_keeps_current_download_path() {
+        // Arrange
+        let categories = live_categories();
+        let desired = DesiredState {
+            categories: categories
+                .keys()
+                .map(|name| {
+                    let desired = DesiredCategory {
+                        save_path: "/downloads/other".to_owned(),
+                        download_path: None,
+                    };
+                    (name.clone(), desired)
+                })
+                .collect(),
+            ..DesiredState::default()
+        };
+
+        // Act
+        let plan = get_plan(&desired, &live_preferences(), &categories, &[]);
+
+        // Assert
+        assert_eq!(plan.changes.len(), categories.len());
+        for change in &plan.changes {
+            let Change::EditCategory { current, desired } = change else {
+                continue;
+            };
+            assert_eq!(desired.save_path, "/downloads/other");
+            assert_eq!(desired.download_path, current.download_path);
+        }
+    }
+
+    #[test]
+    fn deserialize_missing_keys_as_default() {
+        let desired: DesiredState =
+            serde_yaml::from_str("tags: [new]").expect("desired state should deserialize");
+        assert_eq!(
+            desired,
+            DesiredState {
+                tags: BTreeSet::from(["new".to_owned()]),
+                ..DesiredState::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,224 @@
+//! Renaming torrents and the files and folders they contain.
+
+use crate::app::Version;
+use crate::client::{deserialize_response, success_response};
+use crate::{QBittorrentClient, Response};
+use reqwest::Method;
+use rogue_logging::Failure;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// First qBittorrent version where `/torrents/renameFile` takes `oldPath` and `newPath`
+/// instead of a file `id`, and where `/torrents/renameFolder` is available.
+const PATH_RENAME_VERSION: Version = Version::new(4, 3, 3);
+
+impl QBittorrentClient {
+    /// Rename a torrent
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#set-torrent-name>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#set-torrent-name>
+    pub async fn rename_torrent(
+        &self,
+        hash: String,
+        name: String,
+    ) -> Result<Response<bool>, Failure<RenameAction>> {
+        let endpoint = "/torrents/rename";
+        let data = vec![("hash", hash), ("name", name)];
+        let response = self
+            .request_with_login(Method::POST, endpoint, &data)
+            .await
+            .map_err(Failure::wrap(RenameAction::Request))?;
+        match response.status().as_u16() {
+            404 => Err(rename_failure(RenameAction::TorrentNotFound, endpoint, 404)),
+            409 => Err(rename_failure(RenameAction::InvalidName, endpoint, 409)),
+            _ => Ok(success_response(&response)),
+        }
+    }
+
+    /// Rename or move a file within a torrent
+    ///
+    /// Paths are relative to the torrent content root and use `/` as the separator.
+    ///
+    /// Before v4.3.3 the API renamed files by id and only within their folder.
+    /// On those versions the file id is looked up from `old_path`, and
+    /// [`RenameAction::UnsupportedMove`] is returned if `new_path` is in a different folder.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#rename-file>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#rename-file>
+    pub async fn rename_file(
+        &self,
+        hash: String,
+        old_path: String,
+        new_path: String,
+    ) -> Result<Response<bool>, Failure<RenameAction>> {
+        let endpoint = "/torrents/renameFile";
+        let version = self
+            .server_version()
+            .await
+            .map_err(Failure::wrap(RenameAction::Request))?;
+        let data = if version >= PATH_RENAME_VERSION {
+            vec![("hash", hash), ("oldPath", old_path), ("newPath", new_path)]
+        } else {
+            let files = self.get_file_names(&hash).await?;
+            let (id, name) = get_rename_by_id(&files, &old_path, &new_path)?;
+            vec![("hash", hash), ("id", id.to_string()), ("name", name)]
+        };
+        self.send_rename_path(endpoint, &data).await
+    }
+
+    /// Rename or move a folder within a torrent
+    ///
+    /// Paths are relative to the torrent content root and use `/` as the separator.
+    ///
+    /// Added in v4.3.3. Returns [`RenameAction::Request`] wrapping
+    /// [`ClientAction::CheckSupport`](crate::ClientAction::CheckSupport) on older servers.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#rename-folder>
+    pub async fn rename_folder(
+        &self,
+        hash: String,
+        old_path: String,
+        new_path: String,
+    ) -> Result<Response<bool>, Failure<RenameAction>> {
+        let endpoint = "/torrents/renameFolder";
+        self.require_version(PATH_RENAME_VERSION, endpoint)
+            .await
+            .map_err(Failure::wrap(RenameAction::Request))?;
+        let data = vec![("hash", hash), ("oldPath", old_path), ("newPath", new_path)];
+        self.send_rename_path(endpoint, &data).await
+    }
+
+    async fn send_rename_path(
+        &self,
+        endpoint: &str,
+        data: &[(&str, String)],
+    ) -> Result<Response<bool>, Failure<RenameAction>> {
+        let response = self
+            .request_with_login(Method::POST, endpoint, &data)
+            .await
+            .map_err(Failure::wrap(RenameAction::Request))?;
+        match response.status().as_u16() {
+            400 => Err(rename_failure(RenameAction::InvalidPath, endpoint, 400)),
+            404 => Err(rename_failure(RenameAction::TorrentNotFound, endpoint, 404)),
+            409 => Err(rename_failure(RenameAction::PathConflict, endpoint, 409)),
+            _ => Ok(success_response(&response)),
+        }
+    }
+
+    /// Get the relative paths of a torrent's files in file id order.
+    async fn get_file_names(&self, hash: &str) -> Result<Vec<String>, Failure<RenameAction>> {
+        let method = Method::GET;
+        let endpoint = "/torrents/files";
+        let data = vec![("hash", hash)];
+        let response = self
+            .request_with_login(method.clone(), endpoint, &data)
+            .await
+            .map_err(Failure::wrap(RenameAction::Request))?;
+        let files = deserialize_response::<Vec<FileName>>(&method, endpoint, response)
+            .await
+            .and_then(|response| response.get_result("get_file_names"))
+            .map_err(Failure::wrap(RenameAction::Request))?;
+        Ok(files.into_iter().map(|file| file.name).collect())
+    }
+}
+
+/// Name of a file from `/torrents/files`.
+#[derive(Deserialize, Serialize)]
+struct FileName {
+    name: String,
+}
+
+/// Map a path-based rename to the file id and new file name expected before v4.3.3.
+fn get_rename_by_id(
+    files: &[String],
+    old_path: &str,
+    new_path: &str,
+) -> Result<(usize, String), Failure<RenameAction>> {
+    let id = files
+        .iter()
+        .position(|file| file == old_path)
+        .ok_or_else(|| {
+            Failure::from_action(RenameAction::FileNotFound).with("old_path", old_path)
+        })?;
+    let (old_folder, _) = split_path(old_path);
+    let (new_folder, new_name) = split_path(new_path);
+    if old_folder != new_folder {
+        return Err(Failure::from_action(RenameAction::UnsupportedMove)
+            .with("old_path", old_path)
+            .with("new_path", new_path));
+    }
+    Ok((id, new_name.to_owned()))
+}
+
+/// Split a relative path into its folder and file name.
+fn split_path(path: &str) -> (&str, &str) {
+    path.rsplit_once('/').unwrap_or(("", path))
+}
+
+fn rename_failure(action: RenameAction, endpoint: &str, status_code: u16) -> Failure<RenameAction> {
+    Failure::from_action(action)
+        .with("endpoint", endpoint)
+        .with("status_code", status_code.to_string())
+}
+
+/// Errors returned by rename operations
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Error)]
+pub enum RenameAction {
+    #[error("send request")]
+    Request,
+    #[error("find torrent")]
+    TorrentNotFound,
+    #[error("validate name")]
+    InvalidName,
+    #[error("validate path")]
+    InvalidPath,
+    #[error("rename to an unused path")]
+    PathConflict,
+    #[error("find file")]
+    FileNotFound,
+    #[error("move file to another folder on this server version")]
+    UnsupportedMove,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files() -> Vec<String> {
+        vec![
+            "Album/01 - Intro.flac".to_owned(),
+            "Album/02 - Song.flac".to_owned(),
+            "Album/cover.jpg".to_owned(),
+        ]
+    }
+
+    #[test]
+    fn rename_by_id_within_folder() {
+        let result = get_rename_by_id(&files(), "Album/02 - Song.flac", "Album/02 - Track.flac")
+            .expect("rename should be supported");
+        assert_eq!(result, (1, "02 - Track.flac".to_owned()));
+    }
+
+    #[test]
+    fn rename_by_id_missing_file() {
+        let error = get_rename_by_id(&files(), "Album/03 - Outro.flac", "Album/03.flac")
+            .expect_err("file should not be found");
+        assert_eq!(error.action(), &RenameAction::FileNotFound);
+    }
+
+    #[test]
+    fn rename_by_id_to_other_folder() {
+        let error = get_rename_by_id(&files(), "Album/cover.jpg", "Artwork/cover.jpg")
+            .expect_err("move should be unsupported");
+        assert_eq!(error.action(), &RenameAction::UnsupportedMove);
+    }
+
+    #[test]
+    fn split_path_without_folder() {
+        assert_eq!(split_path("file.flac"), ("", "file.flac"));
+        assert_eq!(split_path("a/b/file.flac"), ("a/b", "file.flac"));
+    }
+}
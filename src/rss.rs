@@ -0,0 +1,371 @@
+//! RSS feeds and folders.
+
+use std::collections::BTreeMap;
+
+use crate::client::{deserialize_response, success_response};
+use crate::{QBittorrentClient, Response};
+use reqwest::Method;
+use rogue_logging::Failure;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Separator between the names of nested RSS items in a path.
+pub const RSS_PATH_SEPARATOR: char = '\\';
+
+impl QBittorrentClient {
+    /// Get the tree of RSS folders and feeds
+    ///
+    /// Feed titles, status and articles are only included if `with_data` is `true`.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-all-items>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-all-items>
+    pub async fn get_rss_items(
+        &self,
+        with_data: bool,
+    ) -> Result<Response<RssFolder>, Failure<RssAction>> {
+        let method = Method::GET;
+        let endpoint = "/rss/items";
+        let query = vec![("withData", with_data.to_string())];
+        let response = self
+            .request_with_login(method.clone(), endpoint, &query)
+            .await
+            .map_err(Failure::wrap(RssAction::Request))?;
+        deserialize_response::<RssFolder>(&method, endpoint, response)
+            .await
+            .map_err(Failure::wrap(RssAction::Request))
+    }
+
+    /// Add an RSS folder
+    ///
+    /// Parent folders in `path` must already exist.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#add-folder>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#add-folder>
+    pub async fn add_rss_folder(&self, path: String) -> Result<Response<bool>, Failure<RssAction>> {
+        let data = vec![("path", path)];
+        self.send_rss("/rss/addFolder", &data).await
+    }
+
+    /// Add an RSS feed
+    ///
+    /// `path` is the path of the new feed, or `None` to add it to the root folder named by its URL.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#add-feed>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#add-feed>
+    pub async fn add_rss_feed(
+        &self,
+        url: String,
+        path: Option<String>,
+    ) -> Result<Response<bool>, Failure<RssAction>> {
+        let mut data = vec![("url", url)];
+        if let Some(path) = path {
+            data.push(("path", path));
+        }
+        self.send_rss("/rss/addFeed", &data).await
+    }
+
+    /// Remove an RSS folder or feed
+    ///
+    /// Removing a folder removes everything in it.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#remove-item>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#remove-item>
+    pub async fn remove_rss_item(
+        &self,
+        path: String,
+    ) -> Result<Response<bool>, Failure<RssAction>> {
+        let data = vec![("path", path)];
+        self.send_rss("/rss/removeItem", &data).await
+    }
+
+    /// Move or rename an RSS folder or feed
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#move-item>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#move-item>
+    pub async fn move_rss_item(
+        &self,
+        path: String,
+        destination: String,
+    ) -> Result<Response<bool>, Failure<RssAction>> {
+        let data = vec![("itemPath", path), ("destPath", destination)];
+        self.send_rss("/rss/moveItem", &data).await
+    }
+
+    /// Refresh an RSS feed, or every feed in an RSS folder
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#refresh-item>
+    pub async fn refresh_rss_item(
+        &self,
+        path: String,
+    ) -> Result<Response<bool>, Failure<RssAction>> {
+        let data = vec![("itemPath", path)];
+        self.send_rss("/rss/refreshItem", &data).await
+    }
+
+    /// Mark an article, or every article of an RSS feed or folder, as read
+    ///
+    /// `article_id` is the [`RssArticle::id`] to mark, or `None` to mark every article of the item.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#mark-as-read>
+    pub async fn mark_rss_as_read(
+        &self,
+        path: String,
+        article_id: Option<String>,
+    ) -> Result<Response<bool>, Failure<RssAction>> {
+        let mut data = vec![("itemPath", path)];
+        if let Some(article_id) = article_id {
+            data.push(("articleId", article_id));
+        }
+        self.send_rss("/rss/markAsRead", &data).await
+    }
+
+    pub(crate) async fn send_rss(
+        &self,
+        endpoint: &str,
+        data: &[(&str, String)],
+    ) -> Result<Response<bool>, Failure<RssAction>> {
+        let response = self
+            .request_with_login(Method::POST, endpoint, &data)
+            .await
+            .map_err(Failure::wrap(RssAction::Request))?;
+        if response.status().as_u16() == 409 {
+            let mut failure = Failure::from_action(RssAction::InvalidPath)
+                .with("endpoint", endpoint)
+                .with("status_code", "409");
+            if let Ok(reason) = response.text().await {
+                failure = failure.with("reason", reason);
+            }
+            return Err(failure);
+        }
+        Ok(success_response(&response))
+    }
+}
+
+/// An RSS folder or feed.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/base/rss/rss_folder.cpp>
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/base/rss/rss_feed.cpp>
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum RssItem {
+    /// A feed.
+    Feed(RssFeed),
+    /// A folder of feeds and folders.
+    Folder(RssFolder),
+}
+
+/// An RSS folder of feeds and folders keyed by name.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct RssFolder {
+    /// Feeds and folders keyed by name.
+    pub items: BTreeMap<String, RssItem>,
+}
+
+impl RssFolder {
+    /// Get an item by its path relative to this folder, such as `Trackers\Example`.
+    #[must_use]
+    pub fn get(&self, path: &str) -> Option<&RssItem> {
+        let mut names = path.split(RSS_PATH_SEPARATOR);
+        let mut item = self.items.get(names.next()?)?;
+        for name in names {
+            match item {
+                RssItem::Folder(folder) => item = folder.items.get(name)?,
+                RssItem::Feed(_) => return None,
+            }
+        }
+        Some(item)
+    }
+
+    /// Every feed in this folder and its subfolders keyed by path.
+    #[must_use]
+    pub fn get_feeds(&self) -> BTreeMap<String, &RssFeed> {
+        let mut feeds = BTreeMap::new();
+        self.collect_feeds("", &mut feeds);
+        feeds
+    }
+
+    fn collect_feeds<'a>(&'a self, parent: &str, feeds: &mut BTreeMap<String, &'a RssFeed>) {
+        for (name, item) in &self.items {
+            let path = if parent.is_empty() {
+                name.clone()
+            } else {
+                format!("{parent}{RSS_PATH_SEPARATOR}{name}")
+            };
+            match item {
+                RssItem::Feed(feed) => {
+                    feeds.insert(path, feed);
+                }
+                RssItem::Folder(folder) => folder.collect_feeds(&path, feeds),
+            }
+        }
+    }
+}
+
+/// An RSS feed.
+///
+/// Fields other than `uid` and `url` are only returned when requested with data.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/base/rss/rss_feed.cpp>
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RssFeed {
+    /// Unique id of the feed.
+    pub uid: String,
+
+    /// Feed URL.
+    pub url: String,
+
+    /// Feed title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Last build date reported by the feed.
+    #[serde(
+        default,
+        rename = "lastBuildDate",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_build_date: Option<String>,
+
+    /// Whether the feed is being refreshed.
+    #[serde(default, rename = "isLoading", skip_serializing_if = "Option::is_none")]
+    pub is_loading: Option<bool>,
+
+    /// Whether the last refresh failed.
+    #[serde(default, rename = "hasError", skip_serializing_if = "Option::is_none")]
+    pub has_error: Option<bool>,
+
+    /// Articles of the feed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub articles: Option<Vec<RssArticle>>,
+}
+
+/// An article of an RSS feed.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/base/rss/rss_article.cpp>
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RssArticle {
+    /// Id of the article, unique within its feed.
+    pub id: String,
+
+    /// Publication date.
+    pub date: String,
+
+    /// Title.
+    pub title: String,
+
+    /// Author.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+
+    /// Description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Link to the article.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
+
+    /// URL or magnet link of the torrent.
+    #[serde(
+        default,
+        rename = "torrentURL",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub torrent_url: Option<String>,
+
+    /// Whether the article has been read.
+    #[serde(default, rename = "isRead")]
+    pub is_read: bool,
+}
+
+/// Errors returned by RSS operations
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Error)]
+pub enum RssAction {
+    #[error("send request")]
+    Request,
+    #[error("validate RSS item path")]
+    InvalidPath,
+    #[error("serialize auto-download rule")]
+    SerializeRule,
+    #[error("parse auto-download rule filter")]
+    InvalidRuleFilter,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QBittorrentClientOptions;
+    use crate::tests::init_logger;
+    use insta::assert_yaml_snapshot;
+    use log::trace;
+    use rogue_config::{OptionsProvider, YamlOptionsProvider};
+    use std::error::Error;
+
+    const ITEMS_V5: &str = include_str!("tests/fixtures/rss_items_v5.json");
+    const ITEMS_WITH_DATA_V5: &str = include_str!("tests/fixtures/rss_items_with_data_v5.json");
+
+    #[tokio::test]
+    #[ignore = "integration test requiring API credentials"]
+    async fn get_rss_items() -> Result<(), Box<dyn Error>> {
+        // Arrange
+        init_logger();
+        let options: QBittorrentClientOptions =
+            YamlOptionsProvider::get().map_err(|e| e.to_string())?;
+        let client = QBittorrentClient::from_options(options);
+
+        // Act
+        let response = client.get_rss_items(true).await?;
+        trace!("{}", response.to_json_pretty());
+
+        // Assert
+        let _result = response.get_result("get_rss_items")?;
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_items() {
+        let root: RssFolder = serde_json::from_str(ITEMS_V5).expect("items should deserialize");
+        assert_yaml_snapshot!(root);
+    }
+
+    #[test]
+    fn deserialize_items_with_data() {
+        let root: RssFolder =
+            serde_json::from_str(ITEMS_WITH_DATA_V5).expect("items should deserialize");
+        assert_yaml_snapshot!(root);
+    }
+
+    #[test]
+    fn get_item_by_path() {
+        let root: RssFolder = serde_json::from_str(ITEMS_V5).expect("items should deserialize");
+        assert!(matches!(root.get("Trackers"), Some(RssItem::Folder(_))));
+        let url = match root.get("Trackers\\Example") {
+            Some(RssItem::Feed(feed)) => Some(feed.url.as_str()),
+            _ => None,
+        };
+        assert_eq!(url, Some("https://tracker.example.com/rss?passkey=secret"));
+        assert_eq!(root.get("Trackers\\Missing"), None);
+        assert_eq!(root.get("Releases\\Example"), None);
+    }
+
+    #[test]
+    fn get_feeds() {
+        let root: RssFolder = serde_json::from_str(ITEMS_V5).expect("items should deserialize");
+        let paths: Vec<String> = root.get_feeds().into_keys().collect();
+        assert_eq!(
+            paths,
+            vec!["Releases", "Trackers\\Example", "Trackers\\Private\\Mirror"]
+        );
+    }
+}
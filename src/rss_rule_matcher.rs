@@ -0,0 +1,382 @@
+//! Local evaluation of RSS auto-download rule filters against article titles.
+
+use crate::rss::RssAction;
+use crate::rss_rules::AutoDownloadRule;
+use regex::{Regex, RegexBuilder, escape};
+use rogue_logging::Failure;
+
+/// Matches `1x01-05;07;10-;`, capturing the season and the `;` terminated episodes.
+const EPISODE_FILTER_PATTERN: &str = r"(^\d{1,4})x(.*;$)";
+
+/// Matches episodes such as `S01E02` or `s1.e2`, capturing the season and episode.
+const SEASON_EPISODE_PATTERN: &str = r"\bs0?(\d{1,4})[ -_\.]?e(0?\d{1,4})(?:\D|\b)";
+
+/// Matches episodes such as `1x02`, capturing the season and episode.
+const SEASON_X_EPISODE_PATTERN: &str = r"\b(\d{1,4})x(0?\d{1,4})(?:\D|\b)";
+
+impl AutoDownloadRule {
+    /// Titles matched by the filters of this rule, in the order of `titles`.
+    ///
+    /// See [`RuleMatcher`] for the filters that are evaluated.
+    pub fn get_matching_titles<'a, S: AsRef<str>>(
+        &self,
+        titles: &'a [S],
+    ) -> Result<Vec<&'a str>, Failure<RssAction>> {
+        let matcher = RuleMatcher::new(self)?;
+        Ok(titles
+            .iter()
+            .map(AsRef::as_ref)
+            .filter(|title| matcher.is_match(title))
+            .collect())
+    }
+}
+
+/// Evaluates the filters of an [`AutoDownloadRule`] against article titles the way
+/// qBittorrent does, so rule changes can be tested before setting them on a server.
+///
+/// - `must_contain`, `must_not_contain` and `episode_filter` are evaluated, case-insensitively.
+/// - `enabled`, `affected_feeds`, `ignore_days` and the smart filter are not evaluated.
+/// - Regular expressions use the syntax of the `regex` crate, which does not support
+///   look-around or backreferences.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/base/rss/rss_autodownloadrule.cpp>
+#[derive(Clone, Debug)]
+pub struct RuleMatcher {
+    must_contain: Vec<Expression>,
+    must_not_contain: Vec<Expression>,
+    episode_filter: Option<EpisodeFilter>,
+}
+
+impl RuleMatcher {
+    /// Compile the filters of `rule`.
+    ///
+    /// Returns [`RssAction::InvalidRuleFilter`] if an expression is not a valid regular expression.
+    pub fn new(rule: &AutoDownloadRule) -> Result<Self, Failure<RssAction>> {
+        Ok(Self {
+            must_contain: get_expressions(&rule.must_contain, rule.use_regex)?,
+            must_not_contain: get_expressions(&rule.must_not_contain, rule.use_regex)?,
+            episode_filter: if rule.episode_filter.is_empty() {
+                None
+            } else {
+                Some(EpisodeFilter::new(&rule.episode_filter)?)
+            },
+        })
+    }
+
+    /// Whether `title` passes every filter.
+    #[must_use]
+    pub fn is_match(&self, title: &str) -> bool {
+        let must_contain =
+            self.must_contain.is_empty() || self.must_contain.iter().any(|e| e.is_match(title));
+        let must_not_contain = !self.must_not_contain.iter().any(|e| e.is_match(title));
+        let episode = self
+            .episode_filter
+            .as_ref()
+            .is_none_or(|filter| filter.is_match(title));
+        must_contain && must_not_contain && episode
+    }
+}
+
+/// A complete expression that matches if every one of its patterns matches.
+///
+/// An expression without patterns, such as the empty expression in `x265|`, matches every
+/// title, as a regular expression of that form would.
+#[derive(Clone, Debug)]
+struct Expression(Vec<Regex>);
+
+impl Expression {
+    fn is_match(&self, title: &str) -> bool {
+        self.0.iter().all(|regex| regex.is_match(title))
+    }
+}
+
+/// Split a `must_contain` or `must_not_contain` value into expressions.
+///
+/// - An empty value has no expressions, so it is not a condition.
+/// - A regular expression is a single expression.
+/// - Otherwise expressions are separated by `|` and each expression is a set of whitespace
+///   separated wildcards that must all match.
+fn get_expressions(value: &str, use_regex: bool) -> Result<Vec<Expression>, Failure<RssAction>> {
+    if value.is_empty() {
+        return Ok(Vec::new());
+    }
+    if use_regex {
+        return Ok(vec![Expression(vec![build_regex(value)?])]);
+    }
+    value
+        .split('|')
+        .map(|expression| {
+            expression
+                .split_whitespace()
+                .map(|wildcard| build_regex(&get_wildcard_pattern(wildcard)))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Expression)
+        })
+        .collect()
+}
+
+/// Convert an unanchored wildcard where `*` matches any text and `?` any character.
+fn get_wildcard_pattern(wildcard: &str) -> String {
+    wildcard
+        .chars()
+        .map(|c| match c {
+            '*' => ".*".to_owned(),
+            '?' => ".".to_owned(),
+            _ => escape(&c.to_string()),
+        })
+        .collect()
+}
+
+fn build_regex(pattern: &str) -> Result<Regex, Failure<RssAction>> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(true)
+        .build()
+        .map_err(|e| Failure::new(RssAction::InvalidRuleFilter, e).with("pattern", pattern))
+}
+
+/// A compiled episode filter such as `1x01-05;07;10-;`.
+#[derive(Clone, Debug)]
+struct EpisodeFilter {
+    /// Season of the filter.
+    season: i64,
+    /// Patterns extracting the season and episode of a title for ranges.
+    season_episode: [Regex; 2],
+    /// Episodes and ranges of the filter.
+    episodes: Vec<EpisodeMatch>,
+}
+
+#[derive(Clone, Debug)]
+enum EpisodeMatch {
+    /// A single episode.
+    Single(Regex),
+    /// Episodes from the first to the last, or every later episode and season if `None`.
+    Range(i64, Option<i64>),
+}
+
+impl EpisodeFilter {
+    /// Compile an episode filter.
+    ///
+    /// A filter that is not in the form `<season>x<episodes>;` matches no titles.
+    fn new(filter: &str) -> Result<Self, Failure<RssAction>> {
+        let season_episode = [
+            build_regex(SEASON_EPISODE_PATTERN)?,
+            build_regex(SEASON_X_EPISODE_PATTERN)?,
+        ];
+        let Some(captures) = build_regex(EPISODE_FILTER_PATTERN)?.captures(filter) else {
+            return Ok(Self {
+                season: 0,
+                season_episode,
+                episodes: Vec::new(),
+            });
+        };
+        let season = captures.get(1).map_or("", |m| m.as_str());
+        let episodes = captures.get(2).map_or("", |m| m.as_str());
+        let mut matches = Vec::new();
+        for episode in episodes.split(';').filter(|episode| !episode.is_empty()) {
+            let episode = trim_leading_zeros(episode);
+            if let Some(first) = episode.strip_suffix('-') {
+                matches.push(EpisodeMatch::Range(to_number(first), None));
+            } else if let Some((first, last)) = episode.split_once('-') {
+                let first = to_number(first);
+                let last = to_number(last);
+                if first <= last {
+                    matches.push(EpisodeMatch::Range(first, Some(last)));
+                }
+            } else {
+                let pattern = format!(
+                    r"\b(?:s0?{season}[ -_\.]?e0?{episode}|{season}x0?{episode})(?:\D|\b)",
+                    season = escape(season),
+                    episode = escape(episode),
+                );
+                matches.push(EpisodeMatch::Single(build_regex(&pattern)?));
+            }
+        }
+        Ok(Self {
+            season: to_number(season),
+            season_episode,
+            episodes: matches,
+        })
+    }
+
+    fn is_match(&self, title: &str) -> bool {
+        let theirs = self.get_season_episode(title);
+        self.episodes.iter().any(|episode| match (episode, theirs) {
+            (EpisodeMatch::Single(regex), _) => regex.is_match(title),
+            (EpisodeMatch::Range(first, None), Some((season, episode))) => {
+                (season == self.season && episode >= *first) || season > self.season
+            }
+            (EpisodeMatch::Range(first, Some(last)), Some((season, episode))) => {
+                season == self.season && *first <= episode && episode <= *last
+            }
+            (EpisodeMatch::Range(..), None) => false,
+        })
+    }
+
+    /// Season and episode of a title such as `Show S01E02` or `Show 1x02`.
+    fn get_season_episode(&self, title: &str) -> Option<(i64, i64)> {
+        let captures = self
+            .season_episode
+            .iter()
+            .find_map(|regex| regex.captures(title))?;
+        let season = captures.get(1).map_or("", |m| m.as_str());
+        let episode = captures.get(2).map_or("", |m| m.as_str());
+        Some((to_number(season), to_number(episode)))
+    }
+}
+
+/// Trim leading zeros, keeping a single zero for episode zero.
+fn trim_leading_zeros(episode: &str) -> &str {
+    let trimmed = episode.trim_start_matches('0');
+    if trimmed.is_empty() && !episode.is_empty() {
+        "0"
+    } else {
+        trimmed
+    }
+}
+
+/// Parse a number the way Qt does, returning `0` if it is invalid.
+fn to_number(value: &str) -> i64 {
+    value.parse().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TITLES: [&str; 8] = [
+        "Example Show S01E01 1080p WEB",
+        "Example Show S01E02 720p HDTV",
+        "Example Show S01E05 1080p WEB",
+        "Example Show 1x07 1080p WEB",
+        "Example Show S01E10 1080p WEB",
+        "Example Show S02E01 1080p WEB",
+        "Other Show S01E02 1080p WEB",
+        "Example Show Complete Series 1080p",
+    ];
+
+    fn rule(must_contain: &str, must_not_contain: &str, episode_filter: &str) -> AutoDownloadRule {
+        AutoDownloadRule {
+            must_contain: must_contain.to_owned(),
+            must_not_contain: must_not_contain.to_owned(),
+            episode_filter: episode_filter.to_owned(),
+            ..AutoDownloadRule::default()
+        }
+    }
+
+    fn get_matches(rule: &AutoDownloadRule) -> Vec<&'static str> {
+        rule.get_matching_titles(&TITLES)
+            .expect("rule should compile")
+    }
+
+    #[test]
+    fn empty_rule_matches_all() {
+        assert_eq!(get_matches(&rule("", "", "")), TITLES.to_vec());
+    }
+
+    #[test]
+    fn wildcards_must_all_match() {
+        let matches = get_matches(&rule("example*show 1080?", "", ""));
+        assert_eq!(
+            matches,
+            vec![
+                "Example Show S01E01 1080p WEB",
+                "Example Show S01E05 1080p WEB",
+                "Example Show 1x07 1080p WEB",
+                "Example Show S01E10 1080p WEB",
+                "Example Show S02E01 1080p WEB",
+                "Example Show Complete Series 1080p",
+            ]
+        );
+    }
+
+    #[test]
+    fn any_expression_must_match() {
+        let matches = get_matches(&rule("720p|Other", "", ""));
+        assert_eq!(
+            matches,
+            vec![
+                "Example Show S01E02 720p HDTV",
+                "Other Show S01E02 1080p WEB"
+            ]
+        );
+    }
+
+    #[test]
+    fn must_not_contain_rejects() {
+        let matches = get_matches(&rule("Example Show", "720p|complete", ""));
+        assert_eq!(
+            matches,
+            vec![
+                "Example Show S01E01 1080p WEB",
+                "Example Show S01E05 1080p WEB",
+                "Example Show 1x07 1080p WEB",
+                "Example Show S01E10 1080p WEB",
+                "Example Show S02E01 1080p WEB",
+            ]
+        );
+    }
+
+    #[test]
+    fn must_not_contain_empty_expression_rejects_all() {
+        assert!(get_matches(&rule("", "x265|", "")).is_empty());
+        assert!(get_matches(&rule("", " ", "")).is_empty());
+    }
+
+    #[test]
+    fn regex_expressions() {
+        let rule = AutoDownloadRule {
+            use_regex: true,
+            ..rule(r"^example show s0\de0[12]\b", "hdtv|x265", "")
+        };
+        assert_eq!(
+            get_matches(&rule),
+            vec![
+                "Example Show S01E01 1080p WEB",
+                "Example Show S02E01 1080p WEB"
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_regex() {
+        let rule = AutoDownloadRule {
+            use_regex: true,
+            ..rule("(unclosed", "", "")
+        };
+        let error = RuleMatcher::new(&rule).expect_err("regex should be invalid");
+        assert_eq!(error.action(), &RssAction::InvalidRuleFilter);
+    }
+
+    #[test]
+    fn episode_filter_single_and_range() {
+        let matches = get_matches(&rule("Example Show", "", "1x01;05-07;"));
+        assert_eq!(
+            matches,
+            vec![
+                "Example Show S01E01 1080p WEB",
+                "Example Show S01E05 1080p WEB",
+                "Example Show 1x07 1080p WEB",
+            ]
+        );
+    }
+
+    #[test]
+    fn episode_filter_open_range() {
+        let matches = get_matches(&rule("Example Show", "", "01x07-;"));
+        assert_eq!(
+            matches,
+            vec![
+                "Example Show 1x07 1080p WEB",
+                "Example Show S01E10 1080p WEB",
+                "Example Show S02E01 1080p WEB",
+            ]
+        );
+    }
+
+    #[test]
+    fn episode_filter_invalid_matches_nothing() {
+        assert!(get_matches(&rule("", "", "S01E01")).is_empty());
+        assert!(get_matches(&rule("", "", "1x05-03;")).is_empty());
+    }
+}
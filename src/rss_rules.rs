@@ -0,0 +1,568 @@
+//! RSS auto-download rules.
+
+use std::collections::HashMap;
+use std::mem::take;
+
+use crate::add_torrent::AddTorrentOptions;
+use crate::client::deserialize_response;
+use crate::rss::RssAction;
+use crate::{QBittorrentClient, Response};
+use reqwest::Method;
+use rogue_logging::Failure;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+impl QBittorrentClient {
+    /// Create or replace an RSS auto-download rule
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#set-auto-downloading-rule>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#set-auto-downloading-rule>
+    pub async fn set_rss_rule(
+        &self,
+        name: String,
+        rule: AutoDownloadRule,
+    ) -> Result<Response<bool>, Failure<RssAction>> {
+        let definition = serde_json::to_string(&rule)
+            .map_err(|e| Failure::new(RssAction::SerializeRule, e).with("rule", name.clone()))?;
+        let data = vec![("ruleName", name), ("ruleDef", definition)];
+        self.send_rss("/rss/setRule", &data).await
+    }
+
+    /// Rename an RSS auto-download rule
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#rename-auto-downloading-rule>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#rename-auto-downloading-rule>
+    pub async fn rename_rss_rule(
+        &self,
+        name: String,
+        new_name: String,
+    ) -> Result<Response<bool>, Failure<RssAction>> {
+        let data = vec![("ruleName", name), ("newRuleName", new_name)];
+        self.send_rss("/rss/renameRule", &data).await
+    }
+
+    /// Remove an RSS auto-download rule
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#remove-auto-downloading-rule>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#remove-auto-downloading-rule>
+    pub async fn remove_rss_rule(
+        &self,
+        name: String,
+    ) -> Result<Response<bool>, Failure<RssAction>> {
+        let data = vec![("ruleName", name)];
+        self.send_rss("/rss/removeRule", &data).await
+    }
+
+    /// Get all RSS auto-download rules keyed by name
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-all-auto-downloading-rules>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-all-auto-downloading-rules>
+    pub async fn get_rss_rules(
+        &self,
+    ) -> Result<Response<HashMap<String, AutoDownloadRule>>, Failure<RssAction>> {
+        let method = Method::GET;
+        let endpoint = "/rss/rules";
+        let response = self
+            .request_with_login(method.clone(), endpoint, &())
+            .await
+            .map_err(Failure::wrap(RssAction::Request))?;
+        deserialize_response::<HashMap<String, AutoDownloadRule>>(&method, endpoint, response)
+            .await
+            .map_err(Failure::wrap(RssAction::Request))
+    }
+
+    /// Get the titles of articles matching an RSS auto-download rule keyed by feed name
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-all-articles-matching-a-rule>
+    pub async fn get_rss_matching_articles(
+        &self,
+        name: String,
+    ) -> Result<Response<HashMap<String, Vec<String>>>, Failure<RssAction>> {
+        let method = Method::GET;
+        let endpoint = "/rss/matchingArticles";
+        let query = vec![("ruleName", name)];
+        let response = self
+            .request_with_login(method.clone(), endpoint, &query)
+            .await
+            .map_err(Failure::wrap(RssAction::Request))?;
+        deserialize_response::<HashMap<String, Vec<String>>>(&method, endpoint, response)
+            .await
+            .map_err(Failure::wrap(RssAction::Request))
+    }
+}
+
+/// An RSS auto-download rule.
+///
+/// - Options for torrents added by the rule are [`AddTorrentOptions`]. Only `save_path`,
+///   `category` and `paused` are supported before v4.5. `rename`, `sequential_download` and
+///   `first_last_piece_priority` are not supported.
+/// - Add parameters without an [`AddTorrentOptions`] field, such as the download path, are kept
+///   in [`other_torrent_params`](Self::other_torrent_params) so they are preserved when a rule
+///   is read and set again.
+/// - So are limits [`AddTorrentOptions`] can't represent, such as `-1` for no limit and `-2`
+///   for the global limit.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/base/rss/rss_autodownloadrule.cpp>
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(from = "RuleDefinition", into = "RuleDefinition")]
+pub struct AutoDownloadRule {
+    /// Whether the rule is enabled.
+    pub enabled: bool,
+
+    /// Priority of the rule relative to other rules.
+    pub priority: i64,
+
+    /// Whether `must_contain` and `must_not_contain` are regular expressions.
+    ///
+    /// Otherwise they are `|` separated expressions of whitespace separated wildcards.
+    pub use_regex: bool,
+
+    /// Titles must match this expression. Empty matches all titles.
+    pub must_contain: String,
+
+    /// Titles must not match this expression. Empty matches no titles.
+    pub must_not_contain: String,
+
+    /// Episode filter such as `1x01-05;07;10-;`. Empty matches all titles.
+    pub episode_filter: String,
+
+    /// Whether to skip episodes that have already been downloaded.
+    pub smart_filter: bool,
+
+    /// Episodes already downloaded by the smart filter.
+    pub previously_matched_episodes: Vec<String>,
+
+    /// URLs of the feeds the rule applies to.
+    pub affected_feeds: Vec<String>,
+
+    /// Ignore subsequent matches for this many days. `0` to never ignore.
+    pub ignore_days: i64,
+
+    /// Date of the last match.
+    pub last_match: String,
+
+    /// Options for torrents added by the rule.
+    pub add_options: AddTorrentOptions,
+
+    /// Add parameters without an [`AddTorrentOptions`] field or with a value it can't represent,
+    /// keyed by their API name.
+    ///
+    /// Limits set in [`add_options`](Self::add_options) replace the values kept here.
+    ///
+    /// Only returned by v4.5 and later.
+    pub other_torrent_params: Map<String, Value>,
+}
+
+impl Default for AutoDownloadRule {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            priority: 0,
+            use_regex: false,
+            must_contain: String::new(),
+            must_not_contain: String::new(),
+            episode_filter: String::new(),
+            smart_filter: false,
+            previously_matched_episodes: Vec::new(),
+            affected_feeds: Vec::new(),
+            ignore_days: 0,
+            last_match: String::new(),
+            add_options: AddTorrentOptions::default(),
+            other_torrent_params: Map::new(),
+        }
+    }
+}
+
+/// Rule definition as sent and returned by the API.
+///
+/// Add options are in `torrentParams` since v4.5 and in `savePath`, `assignedCategory` and
+/// `addPaused` before.
+///
+/// Missing fields take the values of [`AutoDownloadRule::default`], except `torrentParams`
+/// which is missing before v4.5.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+struct RuleDefinition {
+    enabled: bool,
+    priority: i64,
+    #[serde(rename = "useRegex")]
+    use_regex: bool,
+    #[serde(rename = "mustContain")]
+    must_contain: String,
+    #[serde(rename = "mustNotContain")]
+    must_not_contain: String,
+    #[serde(rename = "episodeFilter")]
+    episode_filter: String,
+    #[serde(rename = "smartFilter")]
+    smart_filter: bool,
+    #[serde(rename = "previouslyMatchedEpisodes")]
+    previously_matched_episodes: Vec<String>,
+    #[serde(rename = "affectedFeeds")]
+    affected_feeds: Vec<String>,
+    #[serde(rename = "ignoreDays")]
+    ignore_days: i64,
+    #[serde(rename = "lastMatch")]
+    last_match: String,
+    #[serde(rename = "savePath")]
+    save_path: String,
+    #[serde(rename = "assignedCategory")]
+    assigned_category: String,
+    #[serde(rename = "addPaused")]
+    add_paused: Option<bool>,
+    #[serde(rename = "torrentParams", skip_serializing_if = "Option::is_none")]
+    torrent_params: Option<TorrentParams>,
+}
+
+/// Add parameters of a rule since v4.5.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/base/bittorrent/addtorrentparams.cpp>
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+struct TorrentParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    save_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    skip_checking: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stopped: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    upload_limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    download_limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ratio_limit: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seeding_time_limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    use_auto_tmm: Option<bool>,
+    #[serde(flatten)]
+    other: Map<String, Value>,
+}
+
+impl Default for RuleDefinition {
+    fn default() -> Self {
+        let mut definition = Self::from(AutoDownloadRule::default());
+        definition.torrent_params = None;
+        definition
+    }
+}
+
+impl From<RuleDefinition> for AutoDownloadRule {
+    fn from(definition: RuleDefinition) -> Self {
+        let (add_options, other_torrent_params) = match definition.torrent_params {
+            Some(mut params) => {
+                params.keep_negative_limits();
+                let other = take(&mut params.other);
+                (AddTorrentOptions::from(params), other)
+            }
+            None => (
+                AddTorrentOptions {
+                    save_path: non_empty(definition.save_path),
+                    category: non_empty(definition.assigned_category),
+                    paused: definition.add_paused,
+                    ..AddTorrentOptions::default()
+                },
+                Map::new(),
+            ),
+        };
+        Self {
+            enabled: definition.enabled,
+            priority: definition.priority,
+            use_regex: definition.use_regex,
+            must_contain: definition.must_contain,
+            must_not_contain: definition.must_not_contain,
+            episode_filter: definition.episode_filter,
+            smart_filter: definition.smart_filter,
+            previously_matched_episodes: definition.previously_matched_episodes,
+            affected_feeds: definition.affected_feeds,
+            ignore_days: definition.ignore_days,
+            last_match: definition.last_match,
+            add_options,
+            other_torrent_params,
+        }
+    }
+}
+
+impl From<AutoDownloadRule> for RuleDefinition {
+    fn from(rule: AutoDownloadRule) -> Self {
+        Self {
+            enabled: rule.enabled,
+            priority: rule.priority,
+            use_regex: rule.use_regex,
+            must_contain: rule.must_contain,
+            must_not_contain: rule.must_not_contain,
+            episode_filter: rule.episode_filter,
+            smart_filter: rule.smart_filter,
+            previously_matched_episodes: rule.previously_matched_episodes,
+            affected_feeds: rule.affected_feeds,
+            ignore_days: rule.ignore_days,
+            last_match: rule.last_match,
+            save_path: rule.add_options.save_path.clone().unwrap_or_default(),
+            assigned_category: rule.add_options.category.clone().unwrap_or_default(),
+            add_paused: rule.add_options.paused,
+            torrent_params: Some(
+                TorrentParams::from(rule.add_options).with_other(rule.other_torrent_params),
+            ),
+        }
+    }
+}
+
+impl From<TorrentParams> for AddTorrentOptions {
+    fn from(params: TorrentParams) -> Self {
+        Self {
+            save_path: params.save_path.and_then(non_empty),
+            category: params.category.and_then(non_empty),
+            tags: params.tags.filter(|tags| !tags.is_empty()),
+            skip_checking: params.skip_checking,
+            paused: params.stopped,
+            up_limit: params.upload_limit.and_then(to_limit),
+            dl_limit: params.download_limit.and_then(to_limit),
+            ratio_limit: params.ratio_limit,
+            seeding_time_limit: params.seeding_time_limit.and_then(to_limit),
+            automatic_torrent_management: params.use_auto_tmm,
+            ..Self::default()
+        }
+    }
+}
+
+impl TorrentParams {
+    /// Move negative limits, which [`AddTorrentOptions`] can't represent, into `other`.
+    fn keep_negative_limits(&mut self) {
+        for (key, limit) in [
+            ("upload_limit", &mut self.upload_limit),
+            ("download_limit", &mut self.download_limit),
+            ("seeding_time_limit", &mut self.seeding_time_limit),
+        ] {
+            if let Some(limit) = limit.take_if(|limit| *limit < 0) {
+                self.other.insert(key.to_owned(), Value::from(limit));
+            }
+        }
+        if let Some(limit) = self.ratio_limit.take_if(|limit| *limit < 0.0) {
+            self.other
+                .insert("ratio_limit".to_owned(), Value::from(limit));
+        }
+    }
+
+    /// Add the parameters of `other` that are not already set.
+    fn with_other(mut self, mut other: Map<String, Value>) -> Self {
+        for (key, is_set) in [
+            ("upload_limit", self.upload_limit.is_some()),
+            ("download_limit", self.download_limit.is_some()),
+            ("ratio_limit", self.ratio_limit.is_some()),
+            ("seeding_time_limit", self.seeding_time_limit.is_some()),
+        ] {
+            if is_set {
+                other.remove(key);
+            }
+        }
+        self.other = other;
+        self
+    }
+}
+
+impl From<AddTorrentOptions> for TorrentParams {
+    fn from(options: AddTorrentOptions) -> Self {
+        Self {
+            save_path: options.save_path,
+            category: options.category,
+            tags: options.tags,
+            skip_checking: options.skip_checking,
+            stopped: options.paused,
+            upload_limit: options.up_limit.and_then(|limit| i64::try_from(limit).ok()),
+            download_limit: options.dl_limit.and_then(|limit| i64::try_from(limit).ok()),
+            ratio_limit: options.ratio_limit,
+            seeding_time_limit: options
+                .seeding_time_limit
+                .and_then(|limit| i64::try_from(limit).ok()),
+            use_auto_tmm: options.automatic_torrent_management,
+            other: Map::new(),
+        }
+    }
+}
+
+fn non_empty(value: String) -> Option<String> {
+    (!value.is_empty()).then_some(value)
+}
+
+/// Convert a non-negative limit.
+fn to_limit(value: i64) -> Option<usize> {
+    usize::try_from(value).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QBittorrentClientOptions;
+    use crate::tests::init_logger;
+    use insta::assert_yaml_snapshot;
+    use log::trace;
+    use rogue_config::{OptionsProvider, YamlOptionsProvider};
+    use serde_json::json;
+    use std::collections::BTreeMap;
+    use std::error::Error;
+
+    const RULES_V4: &str = include_str!("tests/fixtures/rss_rules_v4.json");
+    const RULES_V5: &str = include_str!("tests/fixtures/rss_rules_v5.json");
+
+    #[tokio::test]
+    #[ignore = "integration test requiring API credentials"]
+    async fn get_rss_rules() -> Result<(), Box<dyn Error>> {
+        // Arrange
+        init_logger();
+        let options: QBittorrentClientOptions =
+            YamlOptionsProvider::get().map_err(|e| e.to_string())?;
+        let client = QBittorrentClient::from_options(options);
+
+        // Act
+        let response = client.get_rss_rules().await?;
+        trace!("{}", response.to_json_pretty());
+
+        // Assert
+        let _result = response.get_result("get_rss_rules")?;
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_v4_fixture() {
+        let rules: BTreeMap<String, AutoDownloadRule> =
+            serde_json::from_str(RULES_V4).expect("v4 fixture should deserialize");
+        assert_yaml_snapshot!(rules);
+    }
+
+    #[test]
+    fn deserialize_v5_fixture() {
+        let rules: BTreeMap<String, AutoDownloadRule> =
+            serde_json::from_str(RULES_V5).expect("v5 fixture should deserialize");
+        assert_yaml_snapshot!(rules);
+    }
+
+    #[test]
+    fn serialize_round_trip() {
+        // Arrange
+        let rule = AutoDownloadRule {
+            must_contain: "Example Show".to_owned(),
+            episode_filter: "1x01-;".to_owned(),
+            affected_feeds: vec!["https://tracker.example.com/rss".to_owned()],
+            add_options: AddTorrentOptions {
+                save_path: Some("/downloads/tv".to_owned()),
+                category: Some("tv".to_owned()),
+                tags: Some(vec!["rss".to_owned()]),
+                paused: Some(true),
+                ratio_limit: Some(2.0),
+                ..AddTorrentOptions::default()
+            },
+            ..AutoDownloadRule::default()
+        };
+
+        // Act
+        let json = serde_json::to_value(&rule).expect("rule should serialize");
+        let result: AutoDownloadRule =
+            serde_json::from_value(json.clone()).expect("rule should deserialize");
+
+        // Assert
+        assert_eq!(
+            json.pointer("/savePath"),
+            Some(&Value::from("/downloads/tv"))
+        );
+        assert_eq!(json.pointer("/assignedCategory"), Some(&Value::from("tv")));
+        assert_eq!(json.pointer("/addPaused"), Some(&Value::from(true)));
+        assert_eq!(
+            json.pointer("/torrentParams/stopped"),
+            Some(&Value::from(true))
+        );
+        assert_eq!(result, rule);
+    }
+
+    #[test]
+    fn round_trip_preserves_other_torrent_params() {
+        // Arrange
+        let rules: BTreeMap<String, AutoDownloadRule> =
+            serde_json::from_str(RULES_V5).expect("v5 fixture should deserialize");
+        let rule = rules
+            .into_values()
+            .find(|rule| !rule.other_torrent_params.is_empty())
+            .expect("fixture should have a rule with other torrent params");
+
+        // Act
+        let json = serde_json::to_value(&rule).expect("rule should serialize");
+
+        // Assert
+        assert_eq!(
+            json.pointer("/torrentParams/share_limit_action"),
+            Some(&Value::from("Default"))
+        );
+        assert_eq!(
+            json.pointer("/torrentParams/inactive_seeding_time_limit"),
+            Some(&Value::from(-2))
+        );
+        assert_eq!(
+            json.pointer("/torrentParams/save_path"),
+            Some(&Value::from("/downloads/music"))
+        );
+    }
+
+    #[test]
+    fn round_trip_preserves_negative_limits() {
+        // Arrange
+        let json = json!({
+            "torrentParams": {
+                "upload_limit": -1,
+                "download_limit": -2,
+                "ratio_limit": -2.0,
+                "seeding_time_limit": -1
+            }
+        });
+
+        // Act
+        let rule: AutoDownloadRule =
+            serde_json::from_value(json.clone()).expect("rule should deserialize");
+        let result = serde_json::to_value(&rule).expect("rule should serialize");
+
+        // Assert
+        assert_eq!(
+            result.pointer("/torrentParams"),
+            json.pointer("/torrentParams")
+        );
+        assert_eq!(rule.add_options.ratio_limit, None);
+    }
+
+    #[test]
+    fn add_options_limits_replace_negative_limits() {
+        // Arrange
+        let json = json!({ "torrentParams": { "ratio_limit": -1.0, "upload_limit": -1 } });
+        let mut rule: AutoDownloadRule =
+            serde_json::from_value(json).expect("rule should deserialize");
+        rule.add_options.ratio_limit = Some(2.0);
+
+        // Act
+        let result = serde_json::to_value(&rule).expect("rule should serialize");
+
+        // Assert
+        assert_eq!(
+            result.pointer("/torrentParams/ratio_limit"),
+            Some(&Value::from(2.0))
+        );
+        assert_eq!(
+            result.pointer("/torrentParams/upload_limit"),
+            Some(&Value::from(-1))
+        );
+    }
+
+    #[test]
+    fn deserialize_missing_fields_as_default() {
+        let rule: AutoDownloadRule = serde_json::from_str("{}").expect("rule should deserialize");
+        assert_eq!(rule, AutoDownloadRule::default());
+        assert!(rule.enabled);
+    }
+}
@@ -0,0 +1,455 @@
+//! Search jobs and streaming of their results.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::client::{deserialize_response, success_response};
+use crate::{QBittorrentClient, Response};
+use futures_util::{Stream, stream::unfold};
+use reqwest::Method;
+use rogue_logging::Failure;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::time::sleep;
+
+impl QBittorrentClient {
+    /// Start a search job
+    ///
+    /// Returns the id of the job.
+    ///
+    /// Returns [`SearchAction::TooManySearches`] if the maximum number of concurrent searches
+    /// is running.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#start-search>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#start-search>
+    pub async fn start_search(
+        &self,
+        query: SearchQuery,
+    ) -> Result<Response<u64>, Failure<SearchAction>> {
+        let method = Method::POST;
+        let endpoint = "/search/start";
+        let response = self
+            .request_with_login(method.clone(), endpoint, &query.to_form())
+            .await
+            .map_err(Failure::wrap(SearchAction::Request))?;
+        if response.status().as_u16() == 409 {
+            return Err(search_failure(SearchAction::TooManySearches, endpoint, 409));
+        }
+        let response = deserialize_response::<SearchJob>(&method, endpoint, response)
+            .await
+            .map_err(Failure::wrap(SearchAction::Request))?;
+        Ok(Response {
+            status_code: response.status_code,
+            result: response.result.map(|job| job.id),
+        })
+    }
+
+    /// Get the status of a search job, or of every search job if `id` is `None`
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-search-status>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-search-status>
+    pub async fn get_search_status(
+        &self,
+        id: Option<u64>,
+    ) -> Result<Response<Vec<SearchStatus>>, Failure<SearchAction>> {
+        let method = Method::GET;
+        let endpoint = "/search/status";
+        let query: Vec<(&str, String)> = id.map(|id| ("id", id.to_string())).into_iter().collect();
+        let response = self
+            .request_with_login(method.clone(), endpoint, &query)
+            .await
+            .map_err(Failure::wrap(SearchAction::Request))?;
+        if response.status().as_u16() == 404 {
+            return Err(search_failure(SearchAction::SearchNotFound, endpoint, 404));
+        }
+        deserialize_response::<Vec<SearchStatus>>(&method, endpoint, response)
+            .await
+            .map_err(Failure::wrap(SearchAction::Request))
+    }
+
+    /// Get results of a search job starting at `offset`
+    ///
+    /// `limit` is the maximum number of results, or `None` for all results.
+    ///
+    /// Returns [`SearchAction::InvalidOffset`] if `offset` is beyond the available results.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-search-results>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-search-results>
+    pub async fn get_search_results(
+        &self,
+        id: u64,
+        offset: usize,
+        limit: Option<usize>,
+    ) -> Result<Response<SearchResults>, Failure<SearchAction>> {
+        let method = Method::GET;
+        let endpoint = "/search/results";
+        let mut query = vec![("id", id.to_string()), ("offset", offset.to_string())];
+        if let Some(limit) = limit {
+            query.push(("limit", limit.to_string()));
+        }
+        let response = self
+            .request_with_login(method.clone(), endpoint, &query)
+            .await
+            .map_err(Failure::wrap(SearchAction::Request))?;
+        match response.status().as_u16() {
+            404 => Err(search_failure(SearchAction::SearchNotFound, endpoint, 404)),
+            409 => Err(search_failure(SearchAction::InvalidOffset, endpoint, 409)
+                .with("offset", offset.to_string())),
+            _ => deserialize_response::<SearchResults>(&method, endpoint, response)
+                .await
+                .map_err(Failure::wrap(SearchAction::Request)),
+        }
+    }
+
+    /// Stop a running search job
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#stop-search>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#stop-search>
+    pub async fn stop_search(&self, id: u64) -> Result<Response<bool>, Failure<SearchAction>> {
+        self.send_search("/search/stop", id).await
+    }
+
+    /// Stop a search job if it is running and delete it with its results
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#delete-search>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#delete-search>
+    pub async fn delete_search(&self, id: u64) -> Result<Response<bool>, Failure<SearchAction>> {
+        self.send_search("/search/delete", id).await
+    }
+
+    /// Stream the results of a search job as they are found
+    ///
+    /// - Results are polled every `poll_interval` and only new results are returned.
+    /// - The stream ends once the job has stopped and every result has been returned.
+    /// - A failed poll is returned as an error and ends the stream.
+    pub fn stream_search_results(
+        &self,
+        id: u64,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<SearchResult, Failure<SearchAction>>> + '_ {
+        let poll = SearchPoll {
+            offset: 0,
+            pending: VecDeque::new(),
+            state: PollState::Start,
+        };
+        unfold(poll, move |mut poll| async move {
+            loop {
+                if let Some(result) = poll.pending.pop_front() {
+                    return Some((Ok(result), poll));
+                }
+                match poll.state {
+                    PollState::Start => {}
+                    PollState::Running => sleep(poll_interval).await,
+                    PollState::Done => return None,
+                }
+                let results = self
+                    .get_search_results(id, poll.offset, None)
+                    .await
+                    .and_then(|response| {
+                        response
+                            .get_result("get_search_results")
+                            .map_err(Failure::wrap(SearchAction::Request))
+                    });
+                match results {
+                    Ok(results) => {
+                        poll.offset += results.results.len();
+                        poll.pending.extend(results.results);
+                        poll.state = match results.status {
+                            SearchState::Running => PollState::Running,
+                            SearchState::Stopped => PollState::Done,
+                        };
+                    }
+                    Err(failure) => {
+                        poll.state = PollState::Done;
+                        return Some((Err(failure), poll));
+                    }
+                }
+            }
+        })
+    }
+
+    async fn send_search(
+        &self,
+        endpoint: &str,
+        id: u64,
+    ) -> Result<Response<bool>, Failure<SearchAction>> {
+        let data = vec![("id", id.to_string())];
+        let response = self
+            .request_with_login(Method::POST, endpoint, &data)
+            .await
+            .map_err(Failure::wrap(SearchAction::Request))?;
+        if response.status().as_u16() == 404 {
+            return Err(search_failure(SearchAction::SearchNotFound, endpoint, 404));
+        }
+        Ok(success_response(&response))
+    }
+}
+
+fn search_failure(action: SearchAction, endpoint: &str, status_code: u16) -> Failure<SearchAction> {
+    Failure::from_action(action)
+        .with("endpoint", endpoint)
+        .with("status_code", status_code.to_string())
+}
+
+/// State of [`QBittorrentClient::stream_search_results`] between polls.
+struct SearchPoll {
+    offset: usize,
+    pending: VecDeque<SearchResult>,
+    state: PollState,
+}
+
+#[derive(Clone, Copy)]
+enum PollState {
+    Start,
+    Running,
+    Done,
+}
+
+/// Options for [`QBittorrentClient::start_search`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    /// Text to search for.
+    pub pattern: String,
+
+    /// Names of the plugins to search with.
+    ///
+    /// `None` searches with every enabled plugin.
+    pub plugins: Option<Vec<String>>,
+
+    /// Category to search in, as supported by the plugins.
+    ///
+    /// `None` searches in all categories.
+    pub category: Option<String>,
+}
+
+impl SearchQuery {
+    /// Build the form parameters for `/search/start`.
+    fn to_form(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("pattern", self.pattern.clone()),
+            (
+                "plugins",
+                self.plugins
+                    .as_ref()
+                    .map_or_else(|| "enabled".to_owned(), |plugins| plugins.join("|")),
+            ),
+            (
+                "category",
+                self.category.clone().unwrap_or_else(|| "all".to_owned()),
+            ),
+        ]
+    }
+}
+
+#[derive(Deserialize)]
+struct SearchJob {
+    id: u64,
+}
+
+/// Whether a search job is running.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum SearchState {
+    /// The job is searching.
+    Running,
+    /// The job has finished or was stopped.
+    Stopped,
+}
+
+/// Status of a search job.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-search-status>
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SearchStatus {
+    /// Id of the job.
+    pub id: u64,
+
+    /// Whether the job is running.
+    pub status: SearchState,
+
+    /// Number of results found.
+    pub total: u64,
+}
+
+/// A page of results of a search job.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-search-results>
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SearchResults {
+    /// Results from the requested offset.
+    pub results: Vec<SearchResult>,
+
+    /// Whether the job is running.
+    pub status: SearchState,
+
+    /// Number of results found.
+    pub total: u64,
+}
+
+#[cfg(feature = "mock")]
+impl SearchResults {
+    /// Create a mock `SearchResults` for testing
+    #[must_use]
+    pub fn mock() -> Self {
+        serde_json::from_str(include_str!("tests/fixtures/search_results_v5.json"))
+            .expect("v5 fixture should deserialize")
+    }
+}
+
+/// A search result.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-search-results>
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/webui/api/searchcontroller.cpp>
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SearchResult {
+    /// Torrent name.
+    #[serde(rename = "fileName")]
+    pub file_name: String,
+
+    /// URL or magnet link of the torrent.
+    ///
+    /// Add it with [`QBittorrentClient::add_torrent_urls`].
+    #[serde(rename = "fileUrl")]
+    pub file_url: String,
+
+    /// Total size (bytes). `-1` if unknown.
+    #[serde(rename = "fileSize")]
+    pub file_size: i64,
+
+    /// Number of seeders. `-1` if unknown.
+    #[serde(rename = "nbSeeders")]
+    pub seeders: i64,
+
+    /// Number of leechers. `-1` if unknown.
+    #[serde(rename = "nbLeechers")]
+    pub leechers: i64,
+
+    /// URL of the site searched.
+    #[serde(rename = "siteUrl")]
+    pub site_url: String,
+
+    /// URL of the description page of the torrent.
+    #[serde(rename = "descrLink")]
+    pub description_url: String,
+
+    /// Name of the plugin that found the result.
+    ///
+    /// Added in v5.0
+    #[serde(rename = "engineName")]
+    pub engine_name: Option<String>,
+
+    /// Publication date (Unix time).
+    ///
+    /// Added in v5.0
+    #[serde(rename = "pubDate")]
+    pub pub_date: Option<i64>,
+}
+
+/// Errors returned by search operations
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Error)]
+pub enum SearchAction {
+    #[error("send request")]
+    Request,
+    #[error("start search job")]
+    TooManySearches,
+    #[error("find search job")]
+    SearchNotFound,
+    #[error("validate search result offset")]
+    InvalidOffset,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QBittorrentClientOptions;
+    use crate::tests::init_logger;
+    use futures_util::StreamExt;
+    use insta::assert_yaml_snapshot;
+    use log::trace;
+    use rogue_config::{OptionsProvider, YamlOptionsProvider};
+    use std::error::Error;
+
+    const RESULTS_V4: &str = include_str!("tests/fixtures/search_results_v4.json");
+    const RESULTS_V5: &str = include_str!("tests/fixtures/search_results_v5.json");
+
+    #[tokio::test]
+    #[ignore = "integration test requiring API credentials"]
+    async fn stream_search_results() -> Result<(), Box<dyn Error>> {
+        // Arrange
+        init_logger();
+        let options: QBittorrentClientOptions =
+            YamlOptionsProvider::get().map_err(|e| e.to_string())?;
+        let client = QBittorrentClient::from_options(options);
+        let query = SearchQuery {
+            pattern: "ubuntu".to_owned(),
+            ..SearchQuery::default()
+        };
+        let id = client
+            .start_search(query)
+            .await?
+            .get_result("start_search")?;
+
+        // Act
+        let results: Vec<_> = client
+            .stream_search_results(id, Duration::from_secs(1))
+            .collect()
+            .await;
+        client.delete_search(id).await?;
+
+        // Assert
+        for result in results {
+            trace!("{}", result?.file_name);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_v4_fixture() {
+        let results: SearchResults =
+            serde_json::from_str(RESULTS_V4).expect("v4 fixture should deserialize");
+        assert_yaml_snapshot!(results);
+    }
+
+    #[test]
+    fn deserialize_v5_fixture() {
+        let results: SearchResults =
+            serde_json::from_str(RESULTS_V5).expect("v5 fixture should deserialize");
+        assert_yaml_snapshot!(results);
+    }
+
+    #[test]
+    fn search_query_form() {
+        let query = SearchQuery {
+            pattern: "example".to_owned(),
+            ..SearchQuery::default()
+        };
+        assert_eq!(
+            query.to_form(),
+            vec![
+                ("pattern", "example".to_owned()),
+                ("plugins", "enabled".to_owned()),
+                ("category", "all".to_owned()),
+            ]
+        );
+        let query = SearchQuery {
+            pattern: "example".to_owned(),
+            plugins: Some(vec!["piratebay".to_owned(), "eztv".to_owned()]),
+            category: Some("tv".to_owned()),
+        };
+        assert_eq!(
+            query.to_form(),
+            vec![
+                ("pattern", "example".to_owned()),
+                ("plugins", "piratebay|eztv".to_owned()),
+                ("category", "tv".to_owned()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,205 @@
+//! Search plugin management.
+
+use crate::client::{deserialize_response, success_response};
+use crate::search::SearchAction;
+use crate::{QBittorrentClient, Response};
+use reqwest::Method;
+use rogue_logging::Failure;
+use serde::{Deserialize, Deserializer, Serialize};
+
+impl QBittorrentClient {
+    /// Get the installed search plugins
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-search-plugins>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-search-plugins>
+    pub async fn get_search_plugins(
+        &self,
+    ) -> Result<Response<Vec<SearchPlugin>>, Failure<SearchAction>> {
+        let method = Method::GET;
+        let endpoint = "/search/plugins";
+        let response = self
+            .request_with_login(method.clone(), endpoint, &())
+            .await
+            .map_err(Failure::wrap(SearchAction::Request))?;
+        deserialize_response::<Vec<SearchPlugin>>(&method, endpoint, response)
+            .await
+            .map_err(Failure::wrap(SearchAction::Request))
+    }
+
+    /// Install search plugins from URLs or paths on the server
+    ///
+    /// Plugins are installed in the background so may not be listed immediately.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#install-search-plugin>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#install-search-plugin>
+    pub async fn install_search_plugin(
+        &self,
+        sources: Vec<String>,
+    ) -> Result<Response<bool>, Failure<SearchAction>> {
+        let data = vec![("sources", sources.join("|"))];
+        self.send_search_plugins("/search/installPlugin", &data)
+            .await
+    }
+
+    /// Uninstall search plugins by name
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#uninstall-search-plugin>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#uninstall-search-plugin>
+    pub async fn uninstall_search_plugin(
+        &self,
+        names: Vec<String>,
+    ) -> Result<Response<bool>, Failure<SearchAction>> {
+        let data = vec![("names", names.join("|"))];
+        self.send_search_plugins("/search/uninstallPlugin", &data)
+            .await
+    }
+
+    /// Enable or disable search plugins by name
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#enable-search-plugin>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#enable-search-plugin>
+    pub async fn enable_search_plugin(
+        &self,
+        names: Vec<String>,
+        enable: bool,
+    ) -> Result<Response<bool>, Failure<SearchAction>> {
+        let data = vec![("names", names.join("|")), ("enable", enable.to_string())];
+        self.send_search_plugins("/search/enablePlugin", &data)
+            .await
+    }
+
+    /// Update every installed search plugin to its latest version
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#update-search-plugins>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#update-search-plugins>
+    pub async fn update_search_plugins(&self) -> Result<Response<bool>, Failure<SearchAction>> {
+        self.send_search_plugins("/search/updatePlugins", &[]).await
+    }
+
+    async fn send_search_plugins(
+        &self,
+        endpoint: &str,
+        data: &[(&str, String)],
+    ) -> Result<Response<bool>, Failure<SearchAction>> {
+        let response = self
+            .request_with_login(Method::POST, endpoint, &data)
+            .await
+            .map_err(Failure::wrap(SearchAction::Request))?;
+        Ok(success_response(&response))
+    }
+}
+
+/// An installed search plugin.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-search-plugins>
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/webui/api/searchcontroller.cpp>
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SearchPlugin {
+    /// Name used to select the plugin.
+    pub name: String,
+
+    /// Display name.
+    #[serde(rename = "fullName")]
+    pub full_name: String,
+
+    /// Whether the plugin is enabled.
+    pub enabled: bool,
+
+    /// Plugin version.
+    pub version: String,
+
+    /// URL of the site searched.
+    pub url: String,
+
+    /// Categories the plugin can search in.
+    #[serde(
+        rename = "supportedCategories",
+        deserialize_with = "deserialize_categories"
+    )]
+    pub supported_categories: Vec<SearchCategory>,
+}
+
+/// A category a search plugin can search in.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SearchCategory {
+    /// Id used to select the category, such as `tv`.
+    pub id: String,
+
+    /// Display name, such as `TV shows`.
+    pub name: String,
+}
+
+/// Deserialize `supportedCategories` which are `{ id, name }` objects on newer servers
+/// and category ids on older servers.
+fn deserialize_categories<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<SearchCategory>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Category {
+        Object(SearchCategory),
+        Id(String),
+    }
+    Ok(Vec::<Category>::deserialize(deserializer)?
+        .into_iter()
+        .map(|category| match category {
+            Category::Object(category) => category,
+            Category::Id(id) => SearchCategory {
+                name: id.clone(),
+                id,
+            },
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QBittorrentClientOptions;
+    use crate::tests::init_logger;
+    use insta::assert_yaml_snapshot;
+    use log::trace;
+    use rogue_config::{OptionsProvider, YamlOptionsProvider};
+    use std::error::Error;
+
+    const PLUGINS_V4: &str = include_str!("tests/fixtures/search_plugins_v4.json");
+    const PLUGINS_V5: &str = include_str!("tests/fixtures/search_plugins_v5.json");
+
+    #[tokio::test]
+    #[ignore = "integration test requiring API credentials"]
+    async fn get_search_plugins() -> Result<(), Box<dyn Error>> {
+        // Arrange
+        init_logger();
+        let options: QBittorrentClientOptions =
+            YamlOptionsProvider::get().map_err(|e| e.to_string())?;
+        let client = QBittorrentClient::from_options(options);
+
+        // Act
+        let response = client.get_search_plugins().await?;
+        trace!("{}", response.to_json_pretty());
+
+        // Assert
+        let _result = response.get_result("get_search_plugins")?;
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_v4_fixture() {
+        let plugins: Vec<SearchPlugin> =
+            serde_json::from_str(PLUGINS_V4).expect("v4 fixture should deserialize");
+        assert_yaml_snapshot!(plugins);
+    }
+
+    #[test]
+    fn deserialize_v5_fixture() {
+        let plugins: Vec<SearchPlugin> =
+            serde_json::from_str(PLUGINS_V5).expect("v5 fixture should deserialize");
+        assert_yaml_snapshot!(plugins);
+    }
+}
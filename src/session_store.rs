@@ -0,0 +1,271 @@
+//! Persisting the session cookie so it can be reused across process restarts.
+
+use std::collections::BTreeMap;
+use std::fs::{OpenOptions, read_to_string, remove_file, rename};
+use std::io::{ErrorKind, Write};
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::PathBuf;
+use std::process;
+
+use crate::QBittorrentClient;
+use log::{trace, warn};
+use reqwest::Url;
+use reqwest::cookie::{CookieStore, Jar};
+use rogue_logging::Failure;
+use thiserror::Error;
+
+/// Storage for the session cookie of each host.
+///
+/// - The cookie is loaded when the client is created so no login is needed at startup.
+///   It is checked before the first request and cleared if it has expired.
+/// - The cookie is saved after each successful login.
+/// - The cookie is cleared when the server answers 403 or on logout.
+///
+/// Cookies are stored as `name=value` pairs as the cookie name varies between
+/// qBittorrent versions.
+pub trait SessionStore: Send + Sync {
+    /// Get the stored cookie for `host`, or `None` if there is none.
+    fn load(&self, host: &str) -> Result<Option<String>, Failure<SessionStoreAction>>;
+
+    /// Store the cookie for `host`, replacing any stored cookie.
+    fn save(&self, host: &str, cookie: &str) -> Result<(), Failure<SessionStoreAction>>;
+
+    /// Remove the stored cookie for `host`.
+    fn clear(&self, host: &str) -> Result<(), Failure<SessionStoreAction>>;
+}
+
+/// A [`SessionStore`] saving cookies to a JSON file of host to cookie.
+///
+/// - On Unix the file is created readable only by the current user.
+/// - The file is replaced atomically so a crash or concurrent process can't leave it
+///   partially written.
+/// - An unreadable file is overwritten on the next save, so the store recovers from a
+///   corrupt file.
+#[derive(Clone, Debug)]
+pub struct FileSessionStore {
+    /// Path of the JSON file.
+    pub path: PathBuf,
+}
+
+impl FileSessionStore {
+    /// Create a store backed by the file at `path`
+    ///
+    /// The file is created on the first save.
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    fn read(&self) -> Result<BTreeMap<String, String>, Failure<SessionStoreAction>> {
+        let json = match read_to_string(&self.path) {
+            Ok(json) => json,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(BTreeMap::new()),
+            Err(e) => {
+                return Err(Failure::new(SessionStoreAction::ReadFile, e)
+                    .with("path", self.path.display().to_string()));
+            }
+        };
+        serde_json::from_str(&json).map_err(|e| {
+            Failure::new(SessionStoreAction::ParseFile, e)
+                .with("path", self.path.display().to_string())
+        })
+    }
+
+    fn write(&self, cookies: &BTreeMap<String, String>) -> Result<(), Failure<SessionStoreAction>> {
+        let json = serde_json::to_string_pretty(cookies)
+            .map_err(Failure::wrap(SessionStoreAction::WriteFile))?;
+        let mut file_name = self.path.file_name().unwrap_or_default().to_os_string();
+        file_name.push(format!(".{}.tmp", process::id()));
+        let temp_path = self.path.with_file_name(file_name);
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+        options
+            .open(&temp_path)
+            .and_then(|mut file| {
+                file.write_all(json.as_bytes())?;
+                file.sync_all()
+            })
+            .and_then(|()| rename(&temp_path, &self.path))
+            .map_err(|e| {
+                let _ = remove_file(&temp_path);
+                Failure::new(SessionStoreAction::WriteFile, e)
+                    .with("path", self.path.display().to_string())
+            })
+    }
+
+    /// Read the stored cookies to update, starting afresh if the file can't be parsed.
+    fn read_for_update(&self) -> Result<BTreeMap<String, String>, Failure<SessionStoreAction>> {
+        match self.read() {
+            Err(failure) if failure.action() == &SessionStoreAction::ParseFile => {
+                warn!("Replacing invalid session file: {failure}");
+                Ok(BTreeMap::new())
+            }
+            result => result,
+        }
+    }
+}
+
+impl SessionStore for FileSessionStore {
+    fn load(&self, host: &str) -> Result<Option<String>, Failure<SessionStoreAction>> {
+        Ok(self.read()?.remove(host))
+    }
+
+    fn save(&self, host: &str, cookie: &str) -> Result<(), Failure<SessionStoreAction>> {
+        let mut cookies = self.read_for_update()?;
+        cookies.insert(host.to_owned(), cookie.to_owned());
+        self.write(&cookies)
+    }
+
+    fn clear(&self, host: &str) -> Result<(), Failure<SessionStoreAction>> {
+        match self.read() {
+            Ok(mut cookies) => {
+                if cookies.remove(host).is_some() {
+                    self.write(&cookies)?;
+                }
+                Ok(())
+            }
+            Err(failure) if failure.action() == &SessionStoreAction::ParseFile => {
+                warn!("Replacing invalid session file: {failure}");
+                self.write(&BTreeMap::new())
+            }
+            Err(failure) => Err(failure),
+        }
+    }
+}
+
+impl QBittorrentClient {
+    /// Save the session cookie to the session store, if configured.
+    ///
+    /// Failures are logged as the session is still usable.
+    pub(crate) fn save_session(&self) {
+        let Some(store) = &self.session_store else {
+            return;
+        };
+        let url = Url::parse(&self.host).expect("host should be a valid URL");
+        let Some(cookie) = self
+            .cookies
+            .cookies(&url)
+            .and_then(|header| header.to_str().ok().map(ToOwned::to_owned))
+        else {
+            return;
+        };
+        trace!("Saving session cookie for {}", self.host);
+        if let Err(failure) = store.save(&self.host, &cookie) {
+            warn!("Unable to save session cookie: {failure}");
+        }
+    }
+
+    /// Remove the session cookie from the session store, if configured.
+    ///
+    /// Failures are logged as a stale cookie is replaced after the next login.
+    pub(crate) fn clear_session(&self) {
+        let Some(store) = &self.session_store else {
+            return;
+        };
+        trace!("Clearing session cookie for {}", self.host);
+        if let Err(failure) = store.clear(&self.host) {
+            warn!("Unable to clear session cookie: {failure}");
+        }
+    }
+}
+
+/// Add the cookie stored for `host` to `cookies`, returning whether a cookie was added.
+///
+/// Failures are logged as the client can still login.
+pub(crate) fn load_session(store: &dyn SessionStore, host: &str, cookies: &Jar) -> bool {
+    let cookie = match store.load(host) {
+        Ok(Some(cookie)) => cookie,
+        Ok(None) => return false,
+        Err(failure) => {
+            warn!("Unable to load session cookie: {failure}");
+            return false;
+        }
+    };
+    let url = Url::parse(host).expect("host should be a valid URL");
+    trace!("Loaded session cookie for {host}");
+    for pair in cookie.split("; ") {
+        cookies.add_cookie_str(&format!("{pair}; Path=/"), &url);
+    }
+    true
+}
+
+/// Errors returned by session store operations
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Error)]
+pub enum SessionStoreAction {
+    #[error("read session file")]
+    ReadFile,
+    #[error("parse session file")]
+    ParseFile,
+    #[error("write session file")]
+    WriteFile,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env::temp_dir;
+    use std::fs::write;
+
+    const HOST: &str = "http://127.0.0.1:8080";
+
+    fn get_store(name: &str) -> FileSessionStore {
+        let path = temp_dir().join(format!("qbittorrent_api_{name}_{}.json", process::id()));
+        let _ = remove_file(&path);
+        FileSessionStore::new(path)
+    }
+
+    #[test]
+    fn file_store_round_trip() -> Result<(), Failure<SessionStoreAction>> {
+        let store = get_store("round_trip");
+        assert_eq!(store.load(HOST)?, None);
+        store.save(HOST, "SID=abc123")?;
+        store.save("http://example.com", "QBT_SID_443=def456")?;
+        assert_eq!(store.load(HOST)?, Some("SID=abc123".to_owned()));
+        store.clear(HOST)?;
+        assert_eq!(store.load(HOST)?, None);
+        assert_eq!(
+            store.load("http://example.com")?,
+            Some("QBT_SID_443=def456".to_owned())
+        );
+        let _ = remove_file(&store.path);
+        Ok(())
+    }
+
+    #[test]
+    fn file_store_invalid_file() {
+        let store = get_store("invalid_file");
+        write(&store.path, "not json").expect("file should be written");
+        let error = store.load(HOST).expect_err("file should not parse");
+        assert_eq!(error.action(), &SessionStoreAction::ParseFile);
+        let _ = remove_file(&store.path);
+    }
+
+    #[test]
+    fn file_store_recovers_from_invalid_file() -> Result<(), Failure<SessionStoreAction>> {
+        let store = get_store("recovers");
+        write(&store.path, "{\"http://127.0.0.1:8080\": \"SID=ab").expect("file should be written");
+        store.save(HOST, "SID=abc123")?;
+        assert_eq!(store.load(HOST)?, Some("SID=abc123".to_owned()));
+        write(&store.path, "").expect("file should be written");
+        store.clear(HOST)?;
+        assert_eq!(store.load(HOST)?, None);
+        let _ = remove_file(&store.path);
+        Ok(())
+    }
+
+    #[test]
+    fn load_session_adds_cookie() -> Result<(), Failure<SessionStoreAction>> {
+        let store = get_store("load_session");
+        store.save(HOST, "SID=abc123")?;
+        let cookies = Jar::default();
+        assert!(load_session(&store, HOST, &cookies));
+        let url = Url::parse(HOST).expect("url should be valid");
+        let header = cookies.cookies(&url).expect("cookie should be loaded");
+        assert_eq!(header.to_str().ok(), Some("SID=abc123"));
+        let _ = remove_file(&store.path);
+        Ok(())
+    }
+}
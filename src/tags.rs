@@ -0,0 +1,172 @@
+//! Tag listing and management.
+
+use crate::app::Version;
+use crate::client::{ClientAction, deserialize_response, join_hashes, success_response};
+use crate::{QBittorrentClient, Response};
+use reqwest::Method;
+use rogue_logging::Failure;
+
+/// First qBittorrent version with `/torrents/setTags`.
+const SET_TAGS_VERSION: Version = Version::new(5, 1, 0);
+
+impl QBittorrentClient {
+    /// Get all tags
+    ///
+    /// Added in v4.2
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-all-tags>
+    pub async fn get_tags(&self) -> Result<Response<Vec<String>>, Failure<ClientAction>> {
+        let method = Method::GET;
+        let endpoint = "/torrents/tags";
+        let response = self
+            .request_with_login(method.clone(), endpoint, &())
+            .await?;
+        deserialize_response::<Vec<String>>(&method, endpoint, response).await
+    }
+
+    /// Create tags that are not assigned to any torrent
+    ///
+    /// Added in v4.2
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#create-tags>
+    pub async fn create_tags(
+        &self,
+        tags: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        let endpoint = "/torrents/createTags";
+        let data = vec![("tags", tags.join(","))];
+        let response = self
+            .request_with_login(Method::POST, endpoint, &data)
+            .await?;
+        Ok(success_response(&response))
+    }
+
+    /// Delete tags and remove them from all torrents
+    ///
+    /// Added in v4.2
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#delete-tags>
+    pub async fn delete_tags(
+        &self,
+        tags: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        let endpoint = "/torrents/deleteTags";
+        let data = vec![("tags", tags.join(","))];
+        let response = self
+            .request_with_login(Method::POST, endpoint, &data)
+            .await?;
+        Ok(success_response(&response))
+    }
+
+    /// Add tags to torrents, creating any tags that do not exist
+    ///
+    /// Added in v4.2
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#add-torrent-tags>
+    pub async fn add_torrent_tags(
+        &self,
+        hashes: Vec<String>,
+        tags: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        self.send_torrent_tags("/torrents/addTags", &hashes, &tags)
+            .await
+    }
+
+    /// Remove tags from torrents
+    ///
+    /// An empty `tags` removes all tags from the torrents.
+    ///
+    /// Added in v4.2
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#remove-torrent-tags>
+    pub async fn remove_torrent_tags(
+        &self,
+        hashes: Vec<String>,
+        tags: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        self.send_torrent_tags("/torrents/removeTags", &hashes, &tags)
+            .await
+    }
+
+    /// Replace the tags of torrents
+    ///
+    /// Added in v5.1. Returns [`ClientAction::CheckSupport`] on older servers.
+    pub async fn set_torrent_tags(
+        &self,
+        hashes: Vec<String>,
+        tags: Vec<String>,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        let endpoint = "/torrents/setTags";
+        self.require_version(SET_TAGS_VERSION, endpoint).await?;
+        self.send_torrent_tags(endpoint, &hashes, &tags).await
+    }
+
+    async fn send_torrent_tags(
+        &self,
+        endpoint: &str,
+        hashes: &[String],
+        tags: &[String],
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        let data = vec![("hashes", join_hashes(hashes)), ("tags", tags.join(","))];
+        let response = self
+            .request_with_login(Method::POST, endpoint, &data)
+            .await?;
+        Ok(success_response(&response))
+    }
+}
+
+/// Split a comma-separated tag string into trimmed, non-empty tags.
+pub(crate) fn split_tags(tags: &str) -> Vec<String> {
+    tags.split(',')
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+        .map(ToOwned::to_owned)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QBittorrentClientOptions;
+    use crate::tests::init_logger;
+    use log::trace;
+    use rogue_config::{OptionsProvider, YamlOptionsProvider};
+    use std::error::Error;
+
+    #[tokio::test]
+    #[ignore = "integration test requiring API credentials"]
+    async fn get_tags() -> Result<(), Box<dyn Error>> {
+        // Arrange
+        init_logger();
+        let options: QBittorrentClientOptions =
+            YamlOptionsProvider::get().map_err(|e| e.to_string())?;
+        let client = QBittorrentClient::from_options(options);
+
+        // Act
+        let response = client.get_tags().await?;
+        trace!("{}", response.to_json_pretty());
+
+        // Assert
+        let _result = response.get_result("get_tags")?;
+        Ok(())
+    }
+
+    #[test]
+    fn split_tags_with_whitespace() {
+        assert_eq!(
+            split_tags("alpha, beta ,gamma delta"),
+            vec!["alpha", "beta", "gamma delta"]
+        );
+    }
+
+    #[test]
+    fn split_tags_empty() {
+        assert!(split_tags("").is_empty());
+        assert!(split_tags(" , ").is_empty());
+    }
+}
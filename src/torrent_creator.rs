@@ -0,0 +1,547 @@
+//! Server-side torrent creation.
+
+use std::time::Instant;
+
+use crate::app::Version;
+use crate::client::{bytes_response, deserialize_response, success_response};
+use crate::wait::WaitOptions;
+use crate::{QBittorrentClient, Response};
+use reqwest::Method;
+use rogue_logging::Failure;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::time::sleep;
+
+/// First qBittorrent version with the `/torrentcreator` endpoints.
+const TORRENT_CREATOR_VERSION: Version = Version::new(5, 0, 0);
+
+impl QBittorrentClient {
+    /// Queue a task to create a torrent from a file or directory on the server
+    ///
+    /// Returns the id of the task.
+    ///
+    /// Returns [`TorrentCreatorAction::TooManyTasks`] if the server has too many active tasks.
+    ///
+    /// Added in v5.0. Returns [`TorrentCreatorAction::Request`] wrapping
+    /// [`ClientAction::CheckSupport`](crate::ClientAction::CheckSupport) on older servers.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#add-task>
+    pub async fn add_torrent_creation_task(
+        &self,
+        task: TorrentCreationTask,
+    ) -> Result<Response<String>, Failure<TorrentCreatorAction>> {
+        let method = Method::POST;
+        let endpoint = "/torrentcreator/addTask";
+        let response = self
+            .request_torrent_creator(method.clone(), endpoint, &task.to_form())
+            .await?;
+        if response.status().as_u16() == 409 {
+            return Err(torrent_creator_failure(
+                TorrentCreatorAction::TooManyTasks,
+                endpoint,
+                409,
+            ));
+        }
+        let response = deserialize_response::<CreationTask>(&method, endpoint, response)
+            .await
+            .map_err(Failure::wrap(TorrentCreatorAction::Request))?;
+        Ok(Response {
+            status_code: response.status_code,
+            result: response.result.map(|task| task.id),
+        })
+    }
+
+    /// Get the status of a torrent creation task, or of every task if `task_id` is `None`
+    ///
+    /// Added in v5.0. Returns [`TorrentCreatorAction::Request`] wrapping
+    /// [`ClientAction::CheckSupport`](crate::ClientAction::CheckSupport) on older servers.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-task-status>
+    pub async fn get_torrent_creation_status(
+        &self,
+        task_id: Option<String>,
+    ) -> Result<Response<Vec<TorrentCreationStatus>>, Failure<TorrentCreatorAction>> {
+        let method = Method::GET;
+        let endpoint = "/torrentcreator/status";
+        let query: Vec<(&str, String)> = task_id.map(|id| ("taskID", id)).into_iter().collect();
+        let response = self
+            .request_torrent_creator(method.clone(), endpoint, &query)
+            .await?;
+        if response.status().as_u16() == 404 {
+            return Err(torrent_creator_failure(
+                TorrentCreatorAction::TaskNotFound,
+                endpoint,
+                404,
+            ));
+        }
+        deserialize_response::<Vec<TorrentCreationStatus>>(&method, endpoint, response)
+            .await
+            .map_err(Failure::wrap(TorrentCreatorAction::Request))
+    }
+
+    /// Get the `.torrent` file created by a finished task
+    ///
+    /// Returns [`TorrentCreatorAction::TaskNotFinished`] if the task is queued, running or failed.
+    ///
+    /// Added in v5.0. Returns [`TorrentCreatorAction::Request`] wrapping
+    /// [`ClientAction::CheckSupport`](crate::ClientAction::CheckSupport) on older servers.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-torrent-file>
+    pub async fn get_torrent_creation_file(
+        &self,
+        task_id: String,
+    ) -> Result<Response<Vec<u8>>, Failure<TorrentCreatorAction>> {
+        let method = Method::GET;
+        let endpoint = "/torrentcreator/torrentFile";
+        let query = vec![("taskID", task_id)];
+        let response = self
+            .request_torrent_creator(method.clone(), endpoint, &query)
+            .await?;
+        match response.status().as_u16() {
+            404 => Err(torrent_creator_failure(
+                TorrentCreatorAction::TaskNotFound,
+                endpoint,
+                404,
+            )),
+            409 => Err(torrent_creator_failure(
+                TorrentCreatorAction::TaskNotFinished,
+                endpoint,
+                409,
+            )),
+            _ => bytes_response(&method, endpoint, response)
+                .await
+                .map_err(Failure::wrap(TorrentCreatorAction::Request)),
+        }
+    }
+
+    /// Delete a torrent creation task, stopping it if it is running
+    ///
+    /// Added in v5.0. Returns [`TorrentCreatorAction::Request`] wrapping
+    /// [`ClientAction::CheckSupport`](crate::ClientAction::CheckSupport) on older servers.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#delete-task>
+    pub async fn delete_torrent_creation_task(
+        &self,
+        task_id: String,
+    ) -> Result<Response<bool>, Failure<TorrentCreatorAction>> {
+        let endpoint = "/torrentcreator/deleteTask";
+        let data = vec![("taskID", task_id)];
+        let response = self
+            .request_torrent_creator(Method::POST, endpoint, &data)
+            .await?;
+        if response.status().as_u16() == 404 {
+            return Err(torrent_creator_failure(
+                TorrentCreatorAction::TaskNotFound,
+                endpoint,
+                404,
+            ));
+        }
+        Ok(success_response(&response))
+    }
+
+    /// Poll a torrent creation task until it completes and get the created `.torrent` file
+    ///
+    /// - The status is polled every [`WaitOptions::poll_interval`].
+    ///   [`WaitOptions::batch_size`] is not used.
+    /// - Returns [`TorrentCreatorAction::TaskFailed`] with the server's error message if the
+    ///   task fails.
+    /// - Returns [`TorrentCreatorAction::TimedOut`] if the task is still queued or running
+    ///   once [`WaitOptions::timeout`] elapses.
+    /// - The task is not deleted, use [`QBittorrentClient::delete_torrent_creation_task`] once
+    ///   the file is no longer needed.
+    pub async fn await_torrent_creation(
+        &self,
+        task_id: String,
+        options: WaitOptions,
+    ) -> Result<Response<Vec<u8>>, Failure<TorrentCreatorAction>> {
+        let start = Instant::now();
+        loop {
+            let statuses = self
+                .get_torrent_creation_status(Some(task_id.clone()))
+                .await?
+                .get_result("get_torrent_creation_status")
+                .map_err(Failure::wrap(TorrentCreatorAction::Request))?;
+            let Some(status) = statuses.into_iter().find(|status| status.id == task_id) else {
+                return Err(Failure::from_action(TorrentCreatorAction::TaskNotFound)
+                    .with("task_id", task_id));
+            };
+            match status.status {
+                TorrentCreationState::Queued | TorrentCreationState::Running => {
+                    if options
+                        .timeout
+                        .is_some_and(|timeout| start.elapsed() >= timeout)
+                    {
+                        return Err(Failure::from_action(TorrentCreatorAction::TimedOut)
+                            .with("task_id", task_id)
+                            .with("status", format!("{:?}", status.status)));
+                    }
+                    sleep(options.poll_interval).await;
+                }
+                TorrentCreationState::Finished => {
+                    return self.get_torrent_creation_file(task_id).await;
+                }
+                TorrentCreationState::Failed => {
+                    return Err(Failure::from_action(TorrentCreatorAction::TaskFailed)
+                        .with("task_id", task_id)
+                        .with("error", status.error_message.unwrap_or_default()));
+                }
+            }
+        }
+    }
+
+    async fn request_torrent_creator<T: Serialize>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        data: &T,
+    ) -> Result<reqwest::Response, Failure<TorrentCreatorAction>> {
+        self.require_version(TORRENT_CREATOR_VERSION, endpoint)
+            .await
+            .map_err(Failure::wrap(TorrentCreatorAction::Request))?;
+        self.request_with_login(method, endpoint, data)
+            .await
+            .map_err(Failure::wrap(TorrentCreatorAction::Request))
+    }
+}
+
+fn torrent_creator_failure(
+    action: TorrentCreatorAction,
+    endpoint: &str,
+    status_code: u16,
+) -> Failure<TorrentCreatorAction> {
+    Failure::from_action(action)
+        .with("endpoint", endpoint)
+        .with("status_code", status_code.to_string())
+}
+
+/// Options for [`QBittorrentClient::add_torrent_creation_task`].
+///
+/// Create with [`TorrentCreationTask::new`] then chain the `with_` methods.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#add-task>
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TorrentCreationTask {
+    /// Path of the file or directory on the server to create the torrent from.
+    pub source_path: String,
+
+    /// Whether the torrent is private.
+    pub private: bool,
+
+    /// Tracker URLs.
+    pub trackers: Vec<String>,
+
+    /// Web seed URLs.
+    pub web_seeds: Vec<String>,
+
+    /// Piece size (bytes).
+    ///
+    /// `None` lets the server choose a piece size.
+    pub piece_size: Option<u32>,
+
+    /// Metadata format.
+    ///
+    /// `None` uses the server default.
+    pub format: Option<TorrentFormat>,
+
+    /// Comment.
+    pub comment: Option<String>,
+
+    /// Source field, often used by private trackers to make the info hash unique.
+    pub source: Option<String>,
+
+    /// Whether to add the created torrent to the server and start seeding it.
+    pub start_seeding: bool,
+}
+
+impl TorrentCreationTask {
+    /// Create a task for the file or directory at `source_path` on the server
+    #[must_use]
+    pub fn new(source_path: impl Into<String>) -> Self {
+        Self {
+            source_path: source_path.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Set whether the torrent is private
+    #[must_use]
+    pub fn with_private(mut self, private: bool) -> Self {
+        self.private = private;
+        self
+    }
+
+    /// Set the tracker URLs
+    #[must_use]
+    pub fn with_trackers(mut self, trackers: Vec<String>) -> Self {
+        self.trackers = trackers;
+        self
+    }
+
+    /// Set the web seed URLs
+    #[must_use]
+    pub fn with_web_seeds(mut self, web_seeds: Vec<String>) -> Self {
+        self.web_seeds = web_seeds;
+        self
+    }
+
+    /// Set the piece size (bytes)
+    #[must_use]
+    pub fn with_piece_size(mut self, piece_size: u32) -> Self {
+        self.piece_size = Some(piece_size);
+        self
+    }
+
+    /// Set the metadata format
+    #[must_use]
+    pub fn with_format(mut self, format: TorrentFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Set the comment
+    #[must_use]
+    pub fn with_comment(mut self, comment: impl Into<String>) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    /// Set the source field
+    #[must_use]
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Set whether to start seeding the created torrent
+    #[must_use]
+    pub fn with_start_seeding(mut self, start_seeding: bool) -> Self {
+        self.start_seeding = start_seeding;
+        self
+    }
+
+    /// Build the form parameters for `/torrentcreator/addTask`.
+    fn to_form(&self) -> Vec<(&'static str, String)> {
+        let mut form = vec![
+            ("sourcePath", self.source_path.clone()),
+            ("private", self.private.to_string()),
+            ("startSeeding", self.start_seeding.to_string()),
+        ];
+        if !self.trackers.is_empty() {
+            form.push(("trackers", self.trackers.join("|")));
+        }
+        if !self.web_seeds.is_empty() {
+            form.push(("urlSeeds", self.web_seeds.join("|")));
+        }
+        if let Some(piece_size) = self.piece_size {
+            form.push(("pieceSize", piece_size.to_string()));
+        }
+        if let Some(format) = self.format {
+            form.push(("format", format.as_str().to_owned()));
+        }
+        if let Some(comment) = &self.comment {
+            form.push(("comment", comment.clone()));
+        }
+        if let Some(source) = &self.source {
+            form.push(("source", source.clone()));
+        }
+        form
+    }
+}
+
+/// Metadata format of a created torrent.
+///
+/// Only supported by servers built with libtorrent 2.0 or later, see
+/// [`QBittorrentClient::is_v2_supported`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TorrentFormat {
+    /// Version 1 metadata.
+    V1,
+    /// Version 2 metadata.
+    V2,
+    /// Both v1 and v2 metadata.
+    Hybrid,
+}
+
+impl TorrentFormat {
+    fn as_str(self) -> &'static str {
+        match self {
+            TorrentFormat::V1 => "v1",
+            TorrentFormat::V2 => "v2",
+            TorrentFormat::Hybrid => "hybrid",
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct CreationTask {
+    #[serde(rename = "taskID")]
+    id: String,
+}
+
+/// Progress of a torrent creation task.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TorrentCreationState {
+    /// Waiting for another task to finish.
+    Queued,
+    /// Hashing the source files.
+    Running,
+    /// The `.torrent` file is ready.
+    Finished,
+    /// Creation failed.
+    Failed,
+}
+
+/// Status of a torrent creation task.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-task-status>
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/webui/api/torrentcreatorcontroller.cpp>
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TorrentCreationStatus {
+    /// Id of the task.
+    #[serde(rename = "taskID")]
+    pub id: String,
+
+    /// Progress of the task.
+    pub status: TorrentCreationState,
+
+    /// Path of the file or directory on the server the torrent is created from.
+    #[serde(rename = "sourcePath")]
+    pub source_path: String,
+
+    /// Piece size (bytes). `0` if chosen by the server.
+    #[serde(rename = "pieceSize")]
+    pub piece_size: u32,
+
+    /// Whether the torrent is private.
+    pub private: bool,
+
+    /// Metadata format.
+    ///
+    /// Only set by servers built with libtorrent 2.0 or later.
+    pub format: Option<TorrentFormat>,
+
+    /// Comment.
+    pub comment: Option<String>,
+
+    /// Source field.
+    pub source: Option<String>,
+
+    /// Tracker URLs.
+    #[serde(default)]
+    pub trackers: Vec<String>,
+
+    /// Web seed URLs.
+    #[serde(rename = "urlSeeds", default)]
+    pub web_seeds: Vec<String>,
+
+    /// Time the task was added.
+    #[serde(rename = "timeAdded")]
+    pub time_added: String,
+
+    /// Time the task started. Only set once running.
+    #[serde(rename = "timeStarted")]
+    pub time_started: Option<String>,
+
+    /// Time the task finished. Only set once finished or failed.
+    #[serde(rename = "timeFinished")]
+    pub time_finished: Option<String>,
+
+    /// Percentage complete. Only set while running.
+    pub progress: Option<f64>,
+
+    /// Reason the task failed. Only set if failed.
+    #[serde(rename = "errorMessage")]
+    pub error_message: Option<String>,
+}
+
+/// Errors returned by torrent creator operations
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Error)]
+pub enum TorrentCreatorAction {
+    #[error("send request")]
+    Request,
+    #[error("add task as too many tasks are active")]
+    TooManyTasks,
+    #[error("find torrent creation task")]
+    TaskNotFound,
+    #[error("get torrent file of unfinished task")]
+    TaskNotFinished,
+    #[error("create torrent")]
+    TaskFailed,
+    #[error("wait for torrent creation")]
+    TimedOut,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QBittorrentClientOptions;
+    use crate::tests::init_logger;
+    use insta::assert_yaml_snapshot;
+    use log::trace;
+    use rogue_config::{OptionsProvider, YamlOptionsProvider};
+    use std::error::Error;
+
+    const STATUS_V5: &str = include_str!("tests/fixtures/torrent_creator_status_v5.json");
+
+    #[tokio::test]
+    #[ignore = "integration test requiring API credentials"]
+    async fn get_torrent_creation_status() -> Result<(), Box<dyn Error>> {
+        // Arrange
+        init_logger();
+        let options: QBittorrentClientOptions =
+            YamlOptionsProvider::get().map_err(|e| e.to_string())?;
+        let client = QBittorrentClient::from_options(options);
+
+        // Act
+        let response = client.get_torrent_creation_status(None).await?;
+        trace!("{}", response.to_json_pretty());
+
+        // Assert
+        let _result = response.get_result("get_torrent_creation_status")?;
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_v5_fixture() {
+        let statuses: Vec<TorrentCreationStatus> =
+            serde_json::from_str(STATUS_V5).expect("v5 fixture should deserialize");
+        assert_yaml_snapshot!(statuses);
+    }
+
+    #[test]
+    fn to_form_minimal() {
+        let task = TorrentCreationTask::new("/downloads/album");
+        assert_eq!(
+            task.to_form(),
+            vec![
+                ("sourcePath", "/downloads/album".to_owned()),
+                ("private", "false".to_owned()),
+                ("startSeeding", "false".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn to_form_all_options() {
+        let task = TorrentCreationTask::new("/downloads/album")
+            .with_private(true)
+            .with_trackers(vec![
+                "https://tracker.example.com/announce".to_owned(),
+                "udp://tracker.example.org:6969".to_owned(),
+            ])
+            .with_web_seeds(vec!["https://example.com/files/".to_owned()])
+            .with_piece_size(262_144)
+            .with_format(TorrentFormat::Hybrid)
+            .with_comment("Example album")
+            .with_source("EXAMPLE")
+            .with_start_seeding(true);
+        assert_yaml_snapshot!(task.to_form());
+    }
+}
@@ -0,0 +1,277 @@
+//! Global transfer info, speed limits and alternative speed limits.
+
+use crate::client::{ClientAction, deserialize_response, success_response};
+use crate::{QBittorrentClient, Response};
+use reqwest::Method;
+use rogue_logging::Failure;
+use serde::{Deserialize, Serialize};
+
+impl QBittorrentClient {
+    /// Get global transfer speeds, session totals and connection status
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-global-transfer-info>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-global-transfer-info>
+    pub async fn get_transfer_info(&self) -> Result<Response<TransferInfo>, Failure<ClientAction>> {
+        let method = Method::GET;
+        let endpoint = "/transfer/info";
+        let response = self
+            .request_with_login(method.clone(), endpoint, &())
+            .await?;
+        deserialize_response::<TransferInfo>(&method, endpoint, response).await
+    }
+
+    /// Set the global download speed limit (bytes/s)
+    ///
+    /// A `limit` of `0` removes the limit.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#set-global-download-limit>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#set-global-download-limit>
+    pub async fn set_global_download_limit(
+        &self,
+        limit: u64,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        self.set_global_limit("/transfer/setDownloadLimit", limit)
+            .await
+    }
+
+    /// Set the global upload speed limit (bytes/s)
+    ///
+    /// A `limit` of `0` removes the limit.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#set-global-upload-limit>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#set-global-upload-limit>
+    pub async fn set_global_upload_limit(
+        &self,
+        limit: u64,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        self.set_global_limit("/transfer/setUploadLimit", limit)
+            .await
+    }
+
+    /// Get whether the global or alternative speed limits are in use
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-alternative-speed-limits-state>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-alternative-speed-limits-state>
+    pub async fn get_speed_limits_mode(
+        &self,
+    ) -> Result<Response<SpeedLimitsMode>, Failure<ClientAction>> {
+        let method = Method::GET;
+        let endpoint = "/transfer/speedLimitsMode";
+        let response = self
+            .request_with_login(method.clone(), endpoint, &())
+            .await?;
+        deserialize_response::<SpeedLimitsMode>(&method, endpoint, response).await
+    }
+
+    /// Toggle between the global and alternative speed limits
+    ///
+    /// Prefer [`set_speed_limits_mode`](Self::set_speed_limits_mode) to set a known state.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#toggle-alternative-speed-limits>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#toggle-alternative-speed-limits>
+    pub async fn toggle_speed_limits_mode(&self) -> Result<Response<bool>, Failure<ClientAction>> {
+        let response = self
+            .request_with_login(Method::POST, "/transfer/toggleSpeedLimitsMode", &())
+            .await?;
+        Ok(success_response(&response))
+    }
+
+    /// Use the global or alternative speed limits
+    ///
+    /// The API can only toggle the mode, so the toggle is only sent if the current
+    /// mode differs from `mode`.
+    pub async fn set_speed_limits_mode(
+        &self,
+        mode: SpeedLimitsMode,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        let response = self.get_speed_limits_mode().await?;
+        let status_code = response.status_code;
+        let current = response.get_result("get_speed_limits_mode")?;
+        if current == mode {
+            return Ok(Response {
+                status_code,
+                result: Some(true),
+            });
+        }
+        self.toggle_speed_limits_mode().await
+    }
+
+    async fn set_global_limit(
+        &self,
+        endpoint: &str,
+        limit: u64,
+    ) -> Result<Response<bool>, Failure<ClientAction>> {
+        let data = vec![("limit", limit.to_string())];
+        let response = self
+            .request_with_login(Method::POST, endpoint, &data)
+            .await?;
+        Ok(success_response(&response))
+    }
+}
+
+/// Global transfer info.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-global-transfer-info>
+/// - <https://github.com/qbittorrent/qBittorrent/blob/release-5.0.0/src/webui/api/transfercontroller.cpp>
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TransferInfo {
+    /// Global download speed (bytes/s).
+    pub dl_info_speed: i64,
+
+    /// Data downloaded this session (bytes).
+    pub dl_info_data: i64,
+
+    /// Global upload speed (bytes/s).
+    pub up_info_speed: i64,
+
+    /// Data uploaded this session (bytes).
+    pub up_info_data: i64,
+
+    /// Global download speed limit (bytes/s). `0` if there is no limit.
+    pub dl_rate_limit: i64,
+
+    /// Global upload speed limit (bytes/s). `0` if there is no limit.
+    pub up_rate_limit: i64,
+
+    /// Number of DHT nodes connected to.
+    pub dht_nodes: i64,
+
+    /// Connection status.
+    pub connection_status: ConnectionStatus,
+
+    /// Last known external IPv4 address.
+    ///
+    /// Not returned by older servers.
+    pub last_external_address_v4: Option<String>,
+
+    /// Last known external IPv6 address.
+    ///
+    /// Not returned by older servers.
+    pub last_external_address_v6: Option<String>,
+}
+
+#[cfg(feature = "mock")]
+impl TransferInfo {
+    /// Create a mock `TransferInfo` for testing
+    #[must_use]
+    pub fn mock() -> Self {
+        Self {
+            dl_info_speed: 0,
+            dl_info_data: 0,
+            up_info_speed: 0,
+            up_info_data: 0,
+            dl_rate_limit: 0,
+            up_rate_limit: 0,
+            dht_nodes: 0,
+            connection_status: ConnectionStatus::Connected,
+            last_external_address_v4: None,
+            last_external_address_v6: None,
+        }
+    }
+}
+
+/// Network connection status.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConnectionStatus {
+    /// Incoming connections are being received.
+    Connected,
+    /// Connected, but no incoming connections have been received.
+    Firewalled,
+    /// Not connected to the network.
+    Disconnected,
+}
+
+/// Which set of global speed limits is in use.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub enum SpeedLimitsMode {
+    /// Regular global speed limits.
+    #[default]
+    Global,
+    /// Alternative speed limits, often used for scheduled throttling.
+    Alternative,
+}
+
+impl TryFrom<u8> for SpeedLimitsMode {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(SpeedLimitsMode::Global),
+            1 => Ok(SpeedLimitsMode::Alternative),
+            _ => Err(format!("unknown speed limits mode: {value}")),
+        }
+    }
+}
+
+impl From<SpeedLimitsMode> for u8 {
+    fn from(mode: SpeedLimitsMode) -> Self {
+        match mode {
+            SpeedLimitsMode::Global => 0,
+            SpeedLimitsMode::Alternative => 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QBittorrentClientOptions;
+    use crate::tests::init_logger;
+    use insta::assert_yaml_snapshot;
+    use log::trace;
+    use rogue_config::{OptionsProvider, YamlOptionsProvider};
+    use std::error::Error;
+
+    const FIXTURE_V4: &str = include_str!("tests/fixtures/transfer_info_v4.json");
+    const FIXTURE_V5: &str = include_str!("tests/fixtures/transfer_info_v5.json");
+
+    #[tokio::test]
+    #[ignore = "integration test requiring API credentials"]
+    async fn get_transfer_info() -> Result<(), Box<dyn Error>> {
+        // Arrange
+        init_logger();
+        let options: QBittorrentClientOptions =
+            YamlOptionsProvider::get().map_err(|e| e.to_string())?;
+        let client = QBittorrentClient::from_options(options);
+
+        // Act
+        let response = client.get_transfer_info().await?;
+        trace!("{}", response.to_json_pretty());
+
+        // Assert
+        let result = response.get_result("get_transfer_info")?;
+        assert!(result.dl_info_data >= 0);
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_v4_fixture() {
+        let info: TransferInfo =
+            serde_json::from_str(FIXTURE_V4).expect("v4 fixture should deserialize");
+        assert_yaml_snapshot!(info);
+    }
+
+    #[test]
+    fn deserialize_v5_fixture() {
+        let info: TransferInfo =
+            serde_json::from_str(FIXTURE_V5).expect("v5 fixture should deserialize");
+        assert_yaml_snapshot!(info);
+    }
+
+    #[test]
+    fn deserialize_speed_limits_mode() {
+        let mode: SpeedLimitsMode = serde_json::from_str("1").expect("mode should deserialize");
+        assert_eq!(mode, SpeedLimitsMode::Alternative);
+        let mode: SpeedLimitsMode = serde_json::from_str("0").expect("mode should deserialize");
+        assert_eq!(mode, SpeedLimitsMode::Global);
+        assert!(serde_json::from_str::<SpeedLimitsMode>("2").is_err());
+    }
+}
@@ -0,0 +1,219 @@
+//! Polling for helpers that wait on long-running torrent operations.
+
+use std::time::{Duration, Instant};
+
+use crate::QBittorrentClient;
+use crate::client::{ClientAction, join_hashes};
+use crate::get_torrents::{FilterOptions, Torrent};
+use rogue_logging::Failure;
+use tokio::time::sleep;
+
+/// Default interval between status polls.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Default maximum time to wait for each batch.
+const DEFAULT_TIMEOUT: Duration = Duration::from_hours(1);
+
+/// Options controlling how a helper polls torrents until an operation completes.
+///
+/// Used by [`QBittorrentClient::move_and_wait`], [`QBittorrentClient::recheck_and_wait`] and
+/// [`QBittorrentClient::await_torrent_creation`].
+#[derive(Clone, Debug)]
+pub struct WaitOptions {
+    /// Time to wait between status polls.
+    pub poll_interval: Duration,
+
+    /// Maximum time to wait for each batch before reporting the remaining torrents as timed out.
+    ///
+    /// Defaults to one hour. `None` waits indefinitely.
+    pub timeout: Option<Duration>,
+
+    /// Maximum number of torrents to operate on at once.
+    ///
+    /// The next batch is only started once every torrent in the current batch has completed,
+    /// which limits the load placed on the server's disks.
+    ///
+    /// `None` operates on all torrents at once.
+    pub batch_size: Option<usize>,
+}
+
+impl WaitOptions {
+    /// Number of torrents per batch, never less than `1`.
+    pub(crate) fn batch_size(&self, total: usize) -> usize {
+        self.batch_size.unwrap_or(total).max(1)
+    }
+}
+
+impl Default for WaitOptions {
+    fn default() -> Self {
+        Self {
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            timeout: Some(DEFAULT_TIMEOUT),
+            batch_size: None,
+        }
+    }
+}
+
+impl QBittorrentClient {
+    /// Poll torrents until `get_status` returns a status for each of them.
+    ///
+    /// - The first poll is immediate, followed by a poll every [`WaitOptions::poll_interval`].
+    /// - `get_status` returns `None` while the operation on a torrent is still in progress.
+    /// - Outcomes are returned in the order of `hashes`.
+    pub(crate) async fn poll_torrents<T, F>(
+        &self,
+        hashes: &[String],
+        options: &WaitOptions,
+        mut get_status: F,
+    ) -> Result<Vec<(String, PollOutcome<T>)>, Failure<ClientAction>>
+    where
+        F: FnMut(&Torrent) -> Option<T>,
+    {
+        let start = Instant::now();
+        let mut outcomes = Vec::with_capacity(hashes.len());
+        let mut pending = hashes.to_vec();
+        while !pending.is_empty() {
+            let filters = FilterOptions {
+                hashes: Some(join_hashes(&pending)),
+                ..FilterOptions::default()
+            };
+            let torrents = self
+                .get_torrents(filters)
+                .await?
+                .get_result("get_torrents")?;
+            let timed_out = options
+                .timeout
+                .is_some_and(|timeout| start.elapsed() >= timeout);
+            let polled = get_poll_outcomes(&mut pending, &torrents, timed_out, &mut get_status);
+            outcomes.extend(polled);
+            if !pending.is_empty() {
+                sleep(options.poll_interval).await;
+            }
+        }
+        outcomes.sort_by_key(|(hash, _)| hashes.iter().position(|item| item == hash));
+        Ok(outcomes)
+    }
+}
+
+/// Get the outcome of each pending torrent that has completed, is missing or has timed out.
+///
+/// Torrents with an outcome are removed from `pending`.
+fn get_poll_outcomes<T, F>(
+    pending: &mut Vec<String>,
+    torrents: &[Torrent],
+    timed_out: bool,
+    get_status: &mut F,
+) -> Vec<(String, PollOutcome<T>)>
+where
+    F: FnMut(&Torrent) -> Option<T>,
+{
+    let mut outcomes = Vec::new();
+    pending.retain(|hash| {
+        let Some(torrent) = torrents.iter().find(|torrent| &torrent.hash == hash) else {
+            outcomes.push((hash.clone(), PollOutcome::NotFound));
+            return false;
+        };
+        let outcome = match get_status(torrent) {
+            Some(status) => PollOutcome::Complete(status),
+            None if timed_out => PollOutcome::TimedOut(Box::new(torrent.clone())),
+            None => return true,
+        };
+        outcomes.push((hash.clone(), outcome));
+        false
+    });
+    outcomes
+}
+
+/// Outcome of polling a single torrent.
+pub(crate) enum PollOutcome<T> {
+    /// Status returned once the operation completed.
+    Complete(T),
+    /// Torrent was not found on the server.
+    NotFound,
+    /// Operation did not complete before [`WaitOptions::timeout`].
+    ///
+    /// Contains the last polled state of the torrent.
+    TimedOut(Box<Torrent>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batch_size_defaults_to_total() {
+        let options = WaitOptions::default();
+        assert_eq!(options.batch_size(250), 250);
+    }
+
+    #[test]
+    fn batch_size_is_never_zero() {
+        let options = WaitOptions {
+            batch_size: Some(0),
+            ..WaitOptions::default()
+        };
+        assert_eq!(options.batch_size(250), 1);
+        assert_eq!(WaitOptions::default().batch_size(0), 1);
+    }
+
+    const FIXTURE_V5: &str = include_str!("tests/fixtures/torrents_info_v5.json");
+
+    fn torrent(hash: &str) -> Torrent {
+        let torrents: Vec<Torrent> =
+            serde_json::from_str(FIXTURE_V5).expect("v5 fixture should deserialize");
+        let mut torrent = torrents
+            .into_iter()
+            .next()
+            .expect("fixture should contain a torrent");
+        hash.clone_into(&mut torrent.hash);
+        torrent
+    }
+
+    fn is_done(torrent: &Torrent) -> Option<()> {
+        (torrent.hash == "done").then_some(())
+    }
+
+    fn summarize(outcomes: &[(String, PollOutcome<()>)]) -> Vec<(&str, &str)> {
+        outcomes
+            .iter()
+            .map(|(hash, outcome)| {
+                let outcome = match outcome {
+                    PollOutcome::Complete(()) => "complete",
+                    PollOutcome::NotFound => "not found",
+                    PollOutcome::TimedOut(_) => "timed out",
+                };
+                (hash.as_str(), outcome)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn poll_outcomes_complete() {
+        let mut pending = vec!["done".to_owned(), "running".to_owned()];
+        let torrents = vec![torrent("done"), torrent("running")];
+        let outcomes = get_poll_outcomes(&mut pending, &torrents, false, &mut is_done);
+        assert_eq!(summarize(&outcomes), vec![("done", "complete")]);
+        assert_eq!(pending, vec!["running".to_owned()]);
+    }
+
+    #[test]
+    fn poll_outcomes_not_found() {
+        let mut pending = vec!["missing".to_owned(), "running".to_owned()];
+        let torrents = vec![torrent("running")];
+        let outcomes = get_poll_outcomes(&mut pending, &torrents, false, &mut is_done);
+        assert_eq!(summarize(&outcomes), vec![("missing", "not found")]);
+        assert_eq!(pending, vec!["running".to_owned()]);
+    }
+
+    #[test]
+    fn poll_outcomes_timed_out() {
+        let mut pending = vec!["done".to_owned(), "running".to_owned()];
+        let torrents = vec![torrent("done"), torrent("running")];
+        let outcomes = get_poll_outcomes(&mut pending, &torrents, true, &mut is_done);
+        assert_eq!(
+            summarize(&outcomes),
+            vec![("done", "complete"), ("running", "timed out")]
+        );
+        assert!(pending.is_empty());
+    }
+}
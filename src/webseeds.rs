@@ -0,0 +1,176 @@
+//! Torrent HTTP web seeds.
+
+use crate::app::Version;
+use crate::client::{deserialize_response, success_response};
+use crate::{QBittorrentClient, Response};
+use reqwest::Method;
+use rogue_logging::Failure;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// First qBittorrent version with `/torrents/addWebSeeds`, `/torrents/editWebSeed`
+/// and `/torrents/removeWebSeeds`.
+const EDIT_WEBSEEDS_VERSION: Version = Version::new(5, 0, 0);
+
+impl QBittorrentClient {
+    /// Get the web seeds of a torrent
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-4.1)#get-torrent-web-seeds>
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-torrent-web-seeds>
+    pub async fn get_webseeds(
+        &self,
+        hash: String,
+    ) -> Result<Response<Vec<WebSeed>>, Failure<WebSeedAction>> {
+        let method = Method::GET;
+        let endpoint = "/torrents/webseeds";
+        let query = vec![("hash", hash)];
+        let response = self
+            .request_with_login(method.clone(), endpoint, &query)
+            .await
+            .map_err(Failure::wrap(WebSeedAction::Request))?;
+        if response.status().as_u16() == 404 {
+            return Err(webseed_failure(
+                WebSeedAction::TorrentNotFound,
+                endpoint,
+                404,
+            ));
+        }
+        deserialize_response::<Vec<WebSeed>>(&method, endpoint, response)
+            .await
+            .map_err(Failure::wrap(WebSeedAction::Request))
+    }
+
+    /// Add web seeds to a torrent
+    ///
+    /// Added in v5.0. Returns [`WebSeedAction::Request`] wrapping
+    /// [`ClientAction::CheckSupport`](crate::ClientAction::CheckSupport) on older servers.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#add-torrent-web-seeds>
+    pub async fn add_webseeds(
+        &self,
+        hash: String,
+        urls: Vec<String>,
+    ) -> Result<Response<bool>, Failure<WebSeedAction>> {
+        let data = vec![("hash", hash), ("urls", urls.join("|"))];
+        self.send_webseeds("/torrents/addWebSeeds", &data).await
+    }
+
+    /// Replace the URL of a web seed of a torrent
+    ///
+    /// Added in v5.0. Returns [`WebSeedAction::Request`] wrapping
+    /// [`ClientAction::CheckSupport`](crate::ClientAction::CheckSupport) on older servers.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#edit-torrent-web-seed>
+    pub async fn edit_webseed(
+        &self,
+        hash: String,
+        old_url: String,
+        new_url: String,
+    ) -> Result<Response<bool>, Failure<WebSeedAction>> {
+        let data = vec![("hash", hash), ("origUrl", old_url), ("newUrl", new_url)];
+        self.send_webseeds("/torrents/editWebSeed", &data).await
+    }
+
+    /// Remove web seeds from a torrent
+    ///
+    /// Added in v5.0. Returns [`WebSeedAction::Request`] wrapping
+    /// [`ClientAction::CheckSupport`](crate::ClientAction::CheckSupport) on older servers.
+    ///
+    /// # See Also
+    /// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#remove-torrent-web-seeds>
+    pub async fn remove_webseeds(
+        &self,
+        hash: String,
+        urls: Vec<String>,
+    ) -> Result<Response<bool>, Failure<WebSeedAction>> {
+        let data = vec![("hash", hash), ("urls", urls.join("|"))];
+        self.send_webseeds("/torrents/removeWebSeeds", &data).await
+    }
+
+    async fn send_webseeds(
+        &self,
+        endpoint: &str,
+        data: &[(&str, String)],
+    ) -> Result<Response<bool>, Failure<WebSeedAction>> {
+        self.require_version(EDIT_WEBSEEDS_VERSION, endpoint)
+            .await
+            .map_err(Failure::wrap(WebSeedAction::Request))?;
+        let response = self
+            .request_with_login(Method::POST, endpoint, &data)
+            .await
+            .map_err(Failure::wrap(WebSeedAction::Request))?;
+        match response.status().as_u16() {
+            400 => Err(webseed_failure(WebSeedAction::InvalidUrl, endpoint, 400)),
+            404 => Err(webseed_failure(
+                WebSeedAction::TorrentNotFound,
+                endpoint,
+                404,
+            )),
+            409 => Err(webseed_failure(
+                WebSeedAction::WebSeedNotFound,
+                endpoint,
+                409,
+            )),
+            _ => Ok(success_response(&response)),
+        }
+    }
+}
+
+fn webseed_failure(
+    action: WebSeedAction,
+    endpoint: &str,
+    status_code: u16,
+) -> Failure<WebSeedAction> {
+    Failure::from_action(action)
+        .with("endpoint", endpoint)
+        .with("status_code", status_code.to_string())
+}
+
+/// An HTTP web seed of a torrent.
+///
+/// # See Also
+/// - <https://github.com/qbittorrent/qBittorrent/wiki/WebUI-API-(qBittorrent-5.0)#get-torrent-web-seeds>
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WebSeed {
+    /// Web seed URL.
+    pub url: String,
+}
+
+/// Errors returned by web seed operations
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Error)]
+pub enum WebSeedAction {
+    #[error("send request")]
+    Request,
+    #[error("find torrent")]
+    TorrentNotFound,
+    #[error("validate web seed URL")]
+    InvalidUrl,
+    #[error("find web seed")]
+    WebSeedNotFound,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_webseeds() {
+        let json = r#"[{"url": "https://example.com/files/"}, {"url": "https://mirror.example.org/album.flac"}]"#;
+        let webseeds: Vec<WebSeed> =
+            serde_json::from_str(json).expect("web seeds should deserialize");
+        assert_eq!(
+            webseeds,
+            vec![
+                WebSeed {
+                    url: "https://example.com/files/".to_owned()
+                },
+                WebSeed {
+                    url: "https://mirror.example.org/album.flac".to_owned()
+                },
+            ]
+        );
+    }
+}